@@ -44,6 +44,9 @@
 //! assert_eq!(iter.sum::<u8>(), 15);
 //! ```
 //!
+//! Inherent methods can be delegated in a separate block, even with the same names as the trait
+//! methods, which they take precedence over in method calls.
+//!
 //! ### With more complicated target
 //!
 //! ```
@@ -75,6 +78,131 @@
 //! assert_eq!(foo.into_boxed_slice().as_ref(), &[1, 2]);
 //! ```
 //!
+//! The receiver is evaluated in each method, so e.g. `self.0.get_or_init(Inner::default)`
+//! initializes a `OnceCell` lazily for any of them.
+//!
+//! The receiver can access fields at any depth, e.g. `self.0.0`. Methods taking `self` by
+//! value move the target out of `self`, which isn't possible when any type along the way
+//! implements `Drop`, or is behind a reference. Values which need to be taken out explicitly,
+//! e.g. from `ManuallyDrop`, can be taken via a function call in the receiver, like
+//! `#[delegate(ManuallyDrop::into_inner(self.0))]`.
+//!
+//! Other expressions like `**self.0` or `&*self.0` are parenthesized before calling the target.
+//! Dereferencing moves the target out only through `Box`, so taking `self` by value isn't
+//! possible through other smart pointers, e.g. `Rc` or a `MutexGuard`.
+//!
+//! Indexing works the same way, e.g. `self.0[0]` or `self.map["key"]`, but can't move the
+//! target out for methods taking `self` by value. Indexing `self` itself, i.e. `self[0]`, goes
+//! through the `Index` impl of the wrapper.
+//!
+//! ### Delegate to enum variants
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! trait Backend {
+//!     fn name(&self) -> String;
+//!     fn store(&mut self, value: u8) -> bool;
+//! }
+//!
+//! # struct Memory;
+//! # struct Disk;
+//! # impl Backend for Memory {
+//! #     fn name(&self) -> String { "memory".to_owned() }
+//! #     fn store(&mut self, _: u8) -> bool { true }
+//! # }
+//! # impl Backend for Disk {
+//! #     fn name(&self) -> String { "disk".to_owned() }
+//! #     fn store(&mut self, _: u8) -> bool { false }
+//! # }
+//! enum AnyBackend {
+//!     Memory(Memory),
+//!     Disk(Disk),
+//! }
+//!
+//! // matches on self, and calls the method on the field of each variant
+//! #[delegate(enum(Memory, Disk))]
+//! impl Backend for AnyBackend {
+//!     fn name(&self) -> String {}
+//!     fn store(&mut self, value: u8) -> bool {}
+//! }
+//!
+//! assert_eq!(AnyBackend::Disk(Disk).name(), "disk");
+//! assert!(AnyBackend::Memory(Memory).store(1));
+//! ```
+//!
+//! The variants must be listed, since the attribute doesn't see the enum, and each of them must
+//! have a single field.
+//!
+//! ### `mut` option
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::cell::RefCell;
+//! struct Foo(RefCell<Vec<u8>>);
+//!
+//! #[delegate(self.0.borrow())]
+//! impl Foo {
+//!     fn len(&self) -> usize {}
+//!
+//!     // calls self.0.borrow_mut().push(value)
+//!     #[delegate(self.0, mut)]
+//!     fn push(&self, value: u8) {}
+//! }
+//!
+//! let foo = Foo(RefCell::new(vec![1]));
+//! foo.push(2);
+//! assert_eq!(foo.len(), 2);
+//! ```
+//!
+//! Methods returning a reference borrowing from `self` through a guard, e.g. `borrow()` or
+//! `lock().unwrap()` in the receiver, are rejected, since the guard is dropped when the method
+//! returns. Such a method can return a guard mapped via e.g. `Ref::map` instead, written by
+//! hand. The `no_guard` option skips the check when the receiver isn't a guard, e.g. a
+//! `borrow()` returning a plain reference.
+//!
+//! ### Accessor for each kind of `self`
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::cell::RefCell;
+//! struct Foo<T> {
+//!     inner: RefCell<Vec<T>>,
+//! }
+//!
+//! // `&self` methods call self.inner.borrow(), `&mut self` methods call
+//! // self.inner.get_mut(), and `self` methods call self.inner.into_inner()
+//! #[delegate(self.inner, ref = borrow, mut = get_mut, owned = into_inner)]
+//! impl<T> Foo<T> {
+//!     fn len(&self) -> usize {}
+//!     fn push(&mut self, value: T) {}
+//!     fn into_boxed_slice(self) -> Box<[T]> {}
+//! }
+//!
+//! let mut foo = Foo { inner: RefCell::new(vec![1]) };
+//! foo.push(2);
+//! assert_eq!(foo.len(), 2);
+//! assert_eq!(foo.into_boxed_slice().as_ref(), &[1, 2]);
+//! ```
+//!
+//! Getters work the same way with `self` as the receiver. A getter returning `&Inner` alone can
+//! only serve `&self` methods.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! struct Foo { inner: Vec<u8> }
+//!
+//! impl Foo {
+//!     fn inner(&self) -> &Vec<u8> { &self.inner }
+//!     fn inner_mut(&mut self) -> &mut Vec<u8> { &mut self.inner }
+//! }
+//!
+//! #[delegate(self, ref = inner, mut = inner_mut)]
+//! impl Foo {
+//!     fn len(&self) -> usize {}
+//!     fn push(&mut self, value: u8) {}
+//! }
+//! ```
+//!
 //! ### `into` and `call` attribute
 //!
 //! ```
@@ -95,9 +223,561 @@
 //!     // calls method, returns ()
 //!     #[call(method)]
 //!     pub fn method_noreturn(&self, num: u32) {}
+//!
+//!     // calls Inner::method(&self.inner, num)
+//!     #[call(Inner::method)]
+//!     pub fn method_by_path(&self, num: u32) -> u32 {}
+//! }
+//! ```
+//!
+//! The body generated for `#[into]` binds the result of the call before converting it, i.e.
+//! `let result = self.inner.method(num); Into::<u64>::into(result)`, so temporaries created
+//! by the receiver expression are dropped first. The type of `result` can be given as
+//! `#[into(Type)]` when it can't be inferred, e.g. together with `#[collect]` below.
+//!
+//! `#[collect]` appends `.collect()` to the call, so methods returning an iterator can be
+//! delegated by methods returning a collection. The return type decides the collection.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::collections::BTreeMap;
+//! struct Foo(BTreeMap<u8, u8>);
+//!
+//! #[delegate(self.0)]
+//! impl Foo {
+//!     #[collect]
+//!     fn keys(&self) -> Vec<&u8> {}
+//! }
+//!
+//! let foo = Foo(vec![(2, 0), (1, 0)].into_iter().collect());
+//! assert_eq!(foo.keys(), [&1, &2]);
+//! ```
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::collections::BTreeMap;
+//! # use std::rc::Rc;
+//! struct Foo(BTreeMap<u8, u8>);
+//!
+//! #[delegate(self.0)]
+//! impl Foo {
+//!     // collects into a Vec<u8>, then converts it into Rc<[u8]>
+//!     #[collect]
+//!     #[into(Vec<u8>)]
+//!     #[call(into_keys)]
+//!     fn into_shared_keys(self) -> Rc<[u8]> {}
+//! }
+//! ```
+//!
+//! With `#[to_owned]`, the result of the target is converted via `ToOwned::to_owned`, e.g.
+//! from `&str` to `String`.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # struct Person;
+//! # impl Person {
+//! #     fn name(&self) -> &str { "" }
+//! # }
+//! struct Wrapper(Person);
+//!
+//! #[delegate(self.0)]
+//! impl Wrapper {
+//!     // calls ToOwned::to_owned(self.0.name())
+//!     #[to_owned]
+//!     fn name(&self) -> String {}
+//! }
+//! ```
+//!
+//! With `#[discard]` on a method returning `()`, the result of the target is discarded via
+//! `let _ = ...`, e.g. for a target marked `#[must_use]`.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::collections::HashSet;
+//! struct Foo(HashSet<u8>);
+//!
+//! #[delegate(self.0)]
+//! impl Foo {
+//!     // calls `let _ = self.0.insert(value);`
+//!     #[discard]
+//!     fn insert(&mut self, value: u8) {}
+//! }
+//! ```
+//!
+//! With `#[into_args]`, each argument is converted via `Into::into`.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::collections::HashMap;
+//! struct Env(HashMap<String, String>);
+//!
+//! #[delegate(self.0)]
+//! impl Env {
+//!     // calls self.0.insert(Into::into(key), Into::into(value))
+//!     #[into_args]
+//!     fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {}
+//! }
+//! ```
+//!
+//! Arguments marked `#[by_ref]` or `#[by_mut]` are passed as `&arg` or `&mut arg`.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::collections::HashSet;
+//! struct Foo(HashSet<String>);
+//!
+//! #[delegate(self.0)]
+//! impl Foo {
+//!     // calls self.0.contains(&name)
+//!     fn contains(&self, #[by_ref] name: String) -> bool {}
+//! }
+//! ```
+//!
+//! Extra arguments can be bound with `#[bind(name = expr, ...)]`. They are passed before the
+//! arguments of the method, in the order listed.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! struct Theme;
+//! struct Renderer;
+//!
+//! impl Renderer {
+//!     fn render(&self, _theme: &Theme, text: &str) -> String {
+//!         text.to_owned()
+//!     }
+//! }
+//!
+//! struct Page {
+//!     renderer: Renderer,
+//!     theme: Theme,
+//! }
+//!
+//! #[delegate(self.renderer)]
+//! impl Page {
+//!     // calls self.renderer.render(&self.theme, text)
+//!     #[bind(theme = &self.theme)]
+//!     fn render(&self, text: &str) -> String {}
+//! }
+//! ```
+//!
+//! `#[call_args(expr, ...)]` gives all the arguments of the call instead, which can use the
+//! arguments of the method, e.g. for passing defaults of the rest.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::time::Duration;
+//! struct Socket;
+//!
+//! impl Socket {
+//!     fn connect(&self, host: &str, port: u16, _timeout: Duration) -> String {
+//!         format!("{}:{}", host, port)
+//!     }
+//! }
+//!
+//! struct Client(Socket);
+//!
+//! #[delegate(self.0)]
+//! impl Client {
+//!     // calls self.0.connect(host, 443, Duration::from_secs(30))
+//!     #[call_args(host, 443, Duration::from_secs(30))]
+//!     fn connect(&self, host: &str) -> String {}
+//! }
+//! # assert_eq!(Client(Socket).connect("example.com"), "example.com:443");
+//! ```
+//!
+//! An `async` method awaits the target, while a method returning `impl Future` or another
+//! future type returns the future of the target as is.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::future::Future;
+//! struct Client;
+//!
+//! impl Client {
+//!     async fn get(&self, key: u32) -> String {
+//!         key.to_string()
+//!     }
+//! }
+//!
+//! struct Wrapper(Client);
+//!
+//! #[delegate(self.0)]
+//! impl Wrapper {
+//!     // calls self.0.get(key).await
+//!     async fn get(&self, key: u32) -> String {}
+//!
+//!     // calls self.0.get(key)
+//!     #[call(get)]
+//!     fn get_future(&self, key: u32) -> impl Future<Output = String> + '_ {}
+//! }
+//! ```
+//!
+//! Methods taking `self: Pin<&Self>` or `self: Pin<&mut Self>` call the target pinned via
+//! `Pin::new`, which requires it to be `Unpin`. Otherwise, `unsafe_pin` projects the pin with
+//! `Pin::map_unchecked` and `Pin::map_unchecked_mut`. It's only sound when the target is never
+//! moved out of a pinned `self`, see [pin projection][projection].
+//!
+//! [projection]: https://doc.rust-lang.org/std/pin/index.html#projections-and-structural-pinning
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::future::Future;
+//! # use std::pin::Pin;
+//! # use std::task::{Context, Poll};
+//! struct Timeout<F> {
+//!     future: F,
+//! }
+//!
+//! // `future` is structurally pinned: it's never moved out of a pinned `Timeout`
+//! #[delegate(self.future, unsafe_pin)]
+//! impl<F: Future> Future for Timeout<F> {
+//!     type Output = F::Output;
+//!     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {}
+//! }
+//! ```
+//!
+//! With `#[call_as_fn]`, the target is called as a function, e.g. a `fn` pointer or a closure.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! struct Callback(fn(u32) -> u32);
+//!
+//! #[delegate(self.0)]
+//! impl Callback {
+//!     // calls (self.0)(x)
+//!     #[call_as_fn]
+//!     fn call(&self, x: u32) -> u32 {}
+//! }
+//!
+//! assert_eq!(Callback(|x| x + 1).call(1), 2);
+//! ```
+//!
+//! With `#[take]`, a `&mut self` method without arguments takes the target out via
+//! `core::mem::take`, leaving its default value behind.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! struct Writer(Vec<u8>);
+//!
+//! #[delegate(self.0)]
+//! impl Writer {
+//!     // calls ::core::mem::take(&mut self.0)
+//!     #[take]
+//!     fn take_buffer(&mut self) -> Vec<u8> {}
+//! }
+//! ```
+//!
+//! ### `to` attribute
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::sync::Mutex;
+//! struct Foo {
+//!     names: Vec<String>,
+//!     ids: Mutex<Vec<u32>>,
+//! }
+//!
+//! // `#[to]` overrides the receiver of a method, keeping the other options
+//! #[delegate(self.names)]
+//! impl Foo {
+//!     fn len(&self) -> usize {}
+//!
+//!     #[to(self.ids.lock().unwrap())]
+//!     #[call(push)]
+//!     fn push_id(&self, id: u32) {}
+//! }
+//! ```
+//!
+//! The receiver can use arguments of the method, and arguments marked `#[skip]` are not
+//! passed to the target.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::collections::HashMap;
+//! struct Tables(HashMap<String, Vec<u32>>);
+//!
+//! #[delegate(self.0)]
+//! impl Tables {
+//!     // calls self.0.get_mut(table).unwrap().push(row)
+//!     #[to(self.0.get_mut(table).unwrap())]
+//!     #[call(push)]
+//!     fn insert(&mut self, #[skip] table: &str, row: u32) {}
 //! }
 //! ```
 //!
+//! ### `rename` attribute
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! struct Foo(Vec<u8>);
+//!
+//! // maps inner method names to wrapper method names,
+//! // `#[call]` on a method takes precedence
+//! #[delegate(self.0)]
+//! #[rename(len = size, capacity = cap)]
+//! impl Foo {
+//!     fn size(&self) -> usize {}
+//!     fn cap(&self) -> usize {}
+//! }
+//!
+//! let foo = Foo(Vec::with_capacity(4));
+//! assert_eq!(foo.size(), 0);
+//! assert_eq!(foo.cap(), 4);
+//! ```
+//!
+//! ### `vis` attribute
+//!
+//! ```
+//! mod foo {
+//!     # use delegate_attr::delegate;
+//!     pub struct Foo(pub Vec<u8>);
+//!
+//!     // sets the visibility of methods without their own, in inherent impls,
+//!     // any visibility like `pub(in path)` can be given
+//!     #[delegate(self.0)]
+//!     #[vis(pub(crate))]
+//!     impl Foo {
+//!         fn len(&self) -> usize {}
+//!         pub fn is_empty(&self) -> bool {}
+//!     }
+//! }
+//!
+//! assert_eq!(foo::Foo(vec![1]).len(), 1);
+//! ```
+//!
+//! ### Methods returning `Self`
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! struct Foo<T>(Vec<T>);
+//!
+//! // the result is wrapped into Self(...), which is inferred from the receiver, or can be
+//! // specified via `wrap = ...`
+//! #[delegate(self.0)]
+//! impl<T: Clone> Foo<T> {
+//!     fn clone(&self) -> Self {}
+//!     fn split_off(&mut self, at: usize) -> Foo<T> {}
+//! }
+//!
+//! let mut foo = Foo(vec![1, 2, 3]);
+//! assert_eq!(foo.split_off(1).0, [2, 3]);
+//! assert_eq!(foo.clone().0, [1]);
+//! ```
+//!
+//! Methods returning `&Self` or `&mut Self` call the target, and return `self`, e.g.
+//! `fn set(&mut self, value: u8) -> &mut Self {}` calls `self.0.set(value); self`. This doesn't
+//! apply when an argument is of type `Self` as well, e.g. `fn max(&self, other: &Self) -> &Self`,
+//! since the result may be that argument.
+//!
+//! Arguments of type `Self`, `&Self` or `&mut Self` are projected to the target the same way
+//! as `self`, so e.g. `fn cmp(&self, other: &Self) -> Ordering {}` calls
+//! `self.0.cmp(&other.0)`. This includes `unwrap`, `expect`, the conversions and `unsafe`:
+//! with `unwrap` it calls `self.0.as_ref().unwrap().cmp(other.0.as_ref().unwrap())`.
+//!
+//! Only `Self` itself or the type of the `impl` block is recognized. Projections like
+//! `Self::Item` or `Self::Output` are left untouched, so the result of the target is returned
+//! as is, even when the associated type is defined as `Self`.
+//!
+//! Methods taking `self` by value can't move the target out of a wrapper implementing `Drop`,
+//! which fails with E0509, the same as destructuring `self` would. They need to be written by
+//! hand, e.g. `fn into_inner(mut self) -> Vec<u8> { mem::take(&mut self.0) }`.
+//!
+//! ### `construct` attribute
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::convert::TryFrom;
+//! # use std::num::TryFromIntError;
+//! struct Wrapper(u8);
+//!
+//! // calls <u8>::try_from(value), and wraps the value into Self
+//! #[delegate(self.0, ty = u8)]
+//! impl TryFrom<u32> for Wrapper {
+//!     type Error = TryFromIntError;
+//!
+//!     #[construct]
+//!     fn try_from(value: u32) -> Result<Self, TryFromIntError> {}
+//! }
+//!
+//! assert_eq!(Wrapper::try_from(1).unwrap().0, 1);
+//! assert!(Wrapper::try_from(256).is_err());
+//! ```
+//!
+//! Other methods without `self` can be delegated with `#[allow_missing_self]`, which calls
+//! `<Type>::method` given `ty = Type`, or the path in `#[call]`, with the arguments only.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! struct Wrapper(u8);
+//!
+//! #[delegate(self.0, ty = u8)]
+//! impl Wrapper {
+//!     #[allow_missing_self]
+//!     fn from_str_radix(src: &str, radix: u32) -> Result<u8, std::num::ParseIntError> {}
+//!
+//!     #[allow_missing_self]
+//!     #[call(std::cmp::max)]
+//!     fn larger(a: u8, b: u8) -> u8 {}
+//! }
+//!
+//! assert_eq!(Wrapper::from_str_radix("ff", 16), Ok(255));
+//! assert_eq!(Wrapper::larger(1, 2), 2);
+//! ```
+//!
+//! Associated consts without a value are taken from the target type given `ty = Type`.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! struct Wrapper(u8);
+//!
+//! #[delegate(self.0, ty = u8)]
+//! impl Wrapper {
+//!     const MAX: u8;
+//!     const ZERO: Self = Wrapper(0);
+//!
+//!     fn count_ones(&self) -> u32 {}
+//! }
+//!
+//! assert_eq!(Wrapper::MAX, 255);
+//! assert_eq!(Wrapper::ZERO.count_ones(), 0);
+//! ```
+//!
+//! Consts of type `Self` wrap the const of the target into `Self`, and consts can also have
+//! their own `#[delegate]`.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! #[derive(PartialEq, Eq)]
+//! struct Level(u8);
+//!
+//! impl Level {
+//!     // Level(<u8>::MAX)
+//!     #[delegate(self.0, ty = u8)]
+//!     const MAX: Self;
+//! }
+//!
+//! const LEVELS: [u8; Level::MAX.0 as usize] = [0; 255];
+//! assert!(matches!(Level(255), Level::MAX));
+//! ```
+//!
+//! Likewise, associated types without a value in trait impls are taken from the target type.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::future::{self, Future};
+//! # use std::pin::Pin;
+//! # use std::task::{Context, Poll};
+//! struct Wrapper(future::Ready<u8>);
+//!
+//! #[delegate(self.0, ty = future::Ready<u8>)]
+//! impl Future for Wrapper {
+//!     // type Output = <future::Ready<u8> as Future>::Output;
+//!     type Output;
+//!     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {}
+//! }
+//! ```
+//!
+//! When the receiver doesn't reference `self`, e.g. a static, the method is called on it.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! # use std::sync::Mutex;
+//! static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+//!
+//! struct Log;
+//!
+//! #[delegate(LOG.lock().unwrap())]
+//! impl Log {
+//!     #[allow_missing_self]
+//!     fn push(message: String) {}
+//! }
+//! ```
+//!
+//! ### Options
+//!
+//! Options can follow the receiver expression in `#[delegate]`, separated by commas:
+//!
+//! * `mut`: call the target via `borrow_mut()` on the receiver.
+//! * `ref = accessor`, `mut = accessor`, `owned = accessor`: call the target via the accessor
+//!   matching how the method takes `self`.
+//! * `ty = Type`: type of the target, used by `#[construct]`, and for consts and associated
+//!   types without a value. Methods without `self` which return `Self`, `Result<Self, _>` or
+//!   `Option<Self>` construct implicitly when it's given.
+//! * `wrap = path`: function to wrap a value of the target into `Self`.
+//! * `unsafe`: access the target inside an `unsafe` block, e.g. for reading a union field. The
+//!   call and its arguments stay outside, e.g. `(unsafe { &self.bits.float }).is_nan()`, so an
+//!   `unsafe fn` target isn't allowed.
+//! * `unwrap`: call the target on the value inside an `Option` or `Result` receiver, which
+//!   panics if there is none. With `#[propagate]` on a method returning `Option` or `Result`,
+//!   it's propagated via `?` instead.
+//! * `expect = "message"`: like `unwrap`, but panics with the message via `expect()`.
+//! * `inline = always`, `inline = hint` or `inline = never`: inline attribute for methods,
+//!   see below. `skip_inline` is a shorthand of `inline = never`. `inline = inherent` is
+//!   `hint` for methods in inherent impls and `never` for methods in trait impls, and is only
+//!   allowed on impl blocks.
+//! * `via = method`: call the target on the result of the method of the receiver, e.g.
+//!   `via = as_slice` for `self.0.as_slice()`.
+//! * `as_ref`, `as_mut`: call `&self` or `&mut self` methods on the result of `as_ref()` or
+//!   `as_mut()` of the receiver. The target type can be given, e.g. `as_ref = str`, when it
+//!   can't be inferred.
+//! * `unsafe_pin`: project `self: Pin<&Self>` and `self: Pin<&mut Self>` to a pinned target,
+//!   see below.
+//! * `allow_clippy`: allow clippy lints commonly triggered by delegated methods, i.e.
+//!   `inline_always`, `needless_lifetimes`, `should_implement_trait` and
+//!   `wrong_self_convention`.
+//! * `must_use_all`: mark methods returning a value `#[must_use]`, unless they have their own.
+//! * `automatically_derived`: mark the trait impl `#[automatically_derived]`.
+//! * `where ...`: bounds to add to the impl block or the method, e.g. `where T: Clone`. It
+//!   must be the last option.
+//! * `use = path`: traits to import in each method, e.g. `use = std::io::{Read, Write}` for
+//!   calling their methods on the target.
+//! * `on_call = path`: function of type `fn(&'static str)`, called with the name of the
+//!   method before delegating, e.g. for logging.
+//! * `no_guard`: don't check for references returned through a guard in the receiver, see
+//!   [`mut` option](#mut-option).
+//! * `debug`: print the generated code to stderr when the macro is expanded, without changing
+//!   it.
+//!
+//! ### Inline
+//!
+//! Delegated methods are marked `#[inline]` unless they have their own `#[inline]`
+//! attribute, or only when the condition doesn't hold for `#[cfg_attr(..., inline)]`.
+//! Methods marked `#[cold]` aren't marked either, unless `#[inline_always]` is given. From
+//! the highest precedence to the lowest, this can be changed by
+//!
+//! * `#[inline_always]` or `#[no_inline]` on the method, which add `#[inline(always)]` or
+//!   nothing respectively,
+//! * the `inline = ...` option of `#[delegate]`,
+//! * the `no-default-inline` feature of this crate, which makes the default add nothing.
+//!
+//! Versions before 0.4 added `#[inline(always)]` by default, which can be restored with
+//! `inline = always`.
+//!
+//! Attributes added by the macro are placed after the method's own attributes, so other
+//! attribute macros on the method see them when they are expanded.
+//!
+//! ### Delegate to multiple targets
+//!
+//! `#[delegate_all]` calls the method on each of the receivers in order. It only supports
+//! methods returning `()`. Each call is passed the same arguments, so they must be `Copy`, e.g.
+//! `fn push(&mut self, value: String) {}` fails with "use of moved value".
+//! `#[rename]` and `#[vis]` on the `impl` block apply the same way as with `#[delegate]`.
+//!
+//! ```
+//! # use delegate_attr::delegate_all;
+//! struct Pair(Vec<u8>, Vec<u8>);
+//!
+//! #[delegate_all(self.0, self.1)]
+//! impl Pair {
+//!     fn push(&mut self, value: u8) {}
+//!     fn clear(&mut self) {}
+//! }
+//!
+//! let mut pair = Pair(vec![], vec![1]);
+//! pair.push(2);
+//! assert_eq!((pair.0, pair.1), (vec![2], vec![1, 2]));
+//! ```
+//!
 //! ### Delegate single method
 //!
 //! ```
@@ -112,59 +792,762 @@
 //! let foo = Foo(vec![1]);
 //! assert_eq!(foo.len(), 1);
 //! ```
+//!
+//! This also works for default methods in a trait definition.
+//!
+//! ```
+//! # use delegate_attr::delegate;
+//! trait HasInner {
+//!     fn inner(&self) -> &Vec<u8>;
+//!
+//!     #[delegate(self.inner())]
+//!     fn len(&self) -> usize {}
+//! }
+//! ```
 
 extern crate proc_macro;
 
 use proc_macro::TokenStream as RawTokenStream;
-use proc_macro2::{Group, Ident, TokenStream, TokenTree};
+use proc_macro2::{Group, Ident, Span, TokenStream, TokenTree};
 use quote::{quote, quote_spanned, ToTokens};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{parse_macro_input, Expr, FnArg, ImplItem, ImplItemFn, ItemImpl, Meta, Pat, ReturnType};
+use syn::{
+    parse_macro_input, Attribute, Expr, ExprField, ExprMethodCall, ExprParen, ExprPath, ExprTry,
+    FnArg, GenericArgument, GenericParam, ImplItem, ImplItemFn, ItemImpl, Lifetime, LitStr, Member,
+    Meta, Pat, Path, PathArguments, ReturnType, Token, TraitItemConst, TraitItemFn, TraitItemType,
+    Type, TypeArray, TypeGroup, TypeParamBound, TypeParen, TypePath, TypeSlice, UseRename, UseTree,
+    Visibility, WhereClause, WherePredicate,
+};
+
+#[proc_macro_attribute]
+pub fn delegate(attr: RawTokenStream, item: RawTokenStream) -> RawTokenStream {
+    let delegate = parse_macro_input!(attr as DelegateArgs);
+    let output = delegate_input(item.into(), &delegate);
+    if delegate.debug {
+        eprintln!("{}", output);
+    }
+    output.into()
+}
+
+#[proc_macro_attribute]
+pub fn delegate_all(attr: RawTokenStream, item: RawTokenStream) -> RawTokenStream {
+    let parser = Punctuated::<Expr, Token![,]>::parse_terminated;
+    let receivers = parse_macro_input!(attr with parser);
+    delegate_all_input(item.into(), receivers.into_iter().collect()).into()
+}
+
+/// Arguments of the `#[delegate]` attribute, i.e. the receiver expression followed by
+/// comma-separated options.
+#[derive(Clone)]
+struct DelegateArgs {
+    receiver: Expr,
+    /// Whether to access the receiver via `borrow_mut()`.
+    borrow_mut: bool,
+    /// Accessors appended to the receiver based on the kind of `self` of each method.
+    accessors: Option<Accessors>,
+    /// Type of the target, used by `#[construct]`, implicit construction, and consts and
+    /// associated types without a value.
+    ty: Option<Type>,
+    /// Function to wrap a value of the target into `Self`.
+    wrap: Option<Path>,
+    /// Whether to access the target inside an `unsafe` block.
+    unsafe_: bool,
+    /// Whether the receiver is an `Option` or a `Result` to unwrap.
+    unwrap: bool,
+    /// Message to unwrap the receiver with via `expect`.
+    expect: Option<LitStr>,
+    /// Inline attribute to add to methods which don't specify one.
+    inline: Option<Inline>,
+    /// Function called with the name of each method before delegating.
+    on_call: Option<Path>,
+    /// Whether to allow clippy lints which delegated methods commonly trigger.
+    allow_clippy: bool,
+    /// Whether to mark trait impls `#[automatically_derived]`.
+    automatically_derived: bool,
+    /// Extra bounds for the impl block or the method.
+    where_clause: Option<WhereClause>,
+    /// Conversion via `AsRef` for `&self` methods, optionally with the target type.
+    as_ref: Option<Option<Type>>,
+    /// Conversion via `AsMut` for `&mut self` methods, optionally with the target type.
+    as_mut: Option<Option<Type>>,
+    /// Variants of `Self` to match on, whose single field is the receiver.
+    variants: Option<Vec<Ident>>,
+    /// Whether to project `Pin<&Self>` and `Pin<&mut Self>` to a pinned target.
+    unsafe_pin: bool,
+    /// Traits to import in each method, e.g. for extension methods of the target.
+    use_: Option<UseTree>,
+    /// Method called on the receiver to get the target, e.g. `as_slice`.
+    via: Option<Ident>,
+    /// Whether to mark methods returning a value `#[must_use]`.
+    must_use_all: bool,
+    /// Whether to print the generated code during expansion.
+    debug: bool,
+    /// Whether to skip checking for references returned through a guard.
+    no_guard: bool,
+}
+
+/// Inline attribute added to delegated methods.
+#[derive(Clone, Copy)]
+enum Inline {
+    /// `#[inline(always)]`
+    Always,
+    /// `#[inline]`
+    Hint,
+    /// No inline attribute.
+    Never,
+    /// `Hint` in inherent impls, `Never` in trait impls.
+    Inherent,
+}
+
+impl Inline {
+    /// The policy when neither the method nor the `#[delegate]` specifies one.
+    fn default_policy() -> Self {
+        if cfg!(feature = "no-default-inline") {
+            Inline::Never
+        } else {
+            Inline::Hint
+        }
+    }
+
+    /// The policy for a method in a trait impl or an inherent impl.
+    fn for_impl(self, is_trait: bool) -> Self {
+        match self {
+            Inline::Inherent if is_trait => Inline::Never,
+            Inline::Inherent => Inline::Hint,
+            _ => self,
+        }
+    }
+
+    /// Content of the attribute, if any.
+    fn meta(self) -> Option<TokenStream> {
+        match self {
+            Inline::Always => Some(quote!(inline(always))),
+            // `Inherent` is resolved via `for_impl` before this.
+            Inline::Hint | Inline::Inherent => Some(quote!(inline)),
+            Inline::Never => None,
+        }
+    }
+}
+
+impl Parse for Inline {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        match ident.to_string().as_str() {
+            "always" => Ok(Inline::Always),
+            "hint" => Ok(Inline::Hint),
+            "never" => Ok(Inline::Never),
+            "inherent" => Ok(Inline::Inherent),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                "expected `always`, `hint`, `never` or `inherent`",
+            )),
+        }
+    }
+}
+
+impl ToTokens for Inline {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(meta) = self.meta() {
+            tokens.extend(quote!(#[#meta]));
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Accessors {
+    ref_: Option<Ident>,
+    mut_: Option<Ident>,
+    owned: Option<Ident>,
+}
+
+/// How a method takes `self`.
+#[derive(Clone, Copy)]
+enum SelfKind {
+    Ref,
+    Mut,
+    Owned,
+}
+
+impl Parse for DelegateArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_RECEIVER: &str = "expected a single receiver expression referencing self";
+        if input.is_empty() {
+            return Err(syn::Error::new(input.span(), EXPECTED_RECEIVER));
+        }
+        // `enum(A, B)` dispatches to the field of each variant, bound as the receiver.
+        let mut variants = None;
+        let receiver = if input.peek(Token![enum]) {
+            input.parse::<Token![enum]>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let list = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            if list.is_empty() {
+                return Err(syn::Error::new(
+                    content.span(),
+                    "expected variants of the enum",
+                ));
+            }
+            variants = Some(list.into_iter().collect());
+            let binding = Ident::new("target", Span::mixed_site());
+            syn::parse_quote!(#binding)
+        } else {
+            parenthesize(input.parse()?)
+        };
+        let mut borrow_mut = false;
+        let mut accessors: Option<Accessors> = None;
+        let mut ty = None;
+        let mut wrap = None;
+        let mut unsafe_ = false;
+        let mut unwrap = false;
+        let mut expect = None;
+        let mut allow_clippy = false;
+        let mut automatically_derived = false;
+        let mut where_clause = None;
+        let mut inline = None;
+        let mut on_call = None;
+        let mut as_ref = None;
+        let mut as_mut = None;
+        let mut unsafe_pin = false;
+        let mut use_ = None;
+        let mut via = None;
+        let mut must_use_all = false;
+        let mut debug = false;
+        let mut no_guard = false;
+        let mut seen = Vec::new();
+        while !input.is_empty() {
+            let comma = input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                return Err(syn::Error::new(comma.span, EXPECTED_RECEIVER));
+            }
+            // Predicates are separated by commas as well, so it must come last.
+            if input.peek(Token![where]) {
+                let mut clause = WhereClause {
+                    where_token: input.parse()?,
+                    predicates: Punctuated::new(),
+                };
+                while !input.is_empty() {
+                    // Another option following, e.g. `inline = never` or `unwrap`.
+                    let ahead = input.fork();
+                    if ahead.call(Ident::parse_any).is_ok()
+                        && (ahead.is_empty() || ahead.peek(Token![=]) || ahead.peek(Token![,]))
+                    {
+                        return Err(input.error("`where` must be the last option"));
+                    }
+                    clause.predicates.push_value(input.parse()?);
+                    if input.is_empty() {
+                        break;
+                    }
+                    clause.predicates.push_punct(input.parse()?);
+                }
+                where_clause = Some(clause);
+                break;
+            }
+            let option = input.call(Ident::parse_any)?;
+            let name = option.to_string();
+            if seen.contains(&name) {
+                return Err(syn::Error::new(option.span(), "duplicate option"));
+            }
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                match name.as_str() {
+                    "ref" | "mut" | "owned" => {
+                        let accessors = accessors.get_or_insert_with(Default::default);
+                        let accessor = match name.as_str() {
+                            "ref" => &mut accessors.ref_,
+                            "mut" => &mut accessors.mut_,
+                            _ => &mut accessors.owned,
+                        };
+                        *accessor = Some(input.call(Ident::parse_any)?);
+                    }
+                    "ty" => ty = Some(input.parse()?),
+                    "wrap" => wrap = Some(input.parse()?),
+                    "inline" => inline = Some(input.parse()?),
+                    "on_call" => on_call = Some(input.parse()?),
+                    "expect" => {
+                        unwrap = true;
+                        expect = Some(input.parse()?);
+                    }
+                    "as_ref" => as_ref = Some(Some(input.parse()?)),
+                    "as_mut" => as_mut = Some(Some(input.parse()?)),
+                    "use" => use_ = Some(anonymize(input.parse()?)),
+                    "via" => via = Some(input.call(Ident::parse_any)?),
+                    _ => return Err(syn::Error::new(option.span(), "unknown option")),
+                }
+            } else {
+                match name.as_str() {
+                    "mut" => borrow_mut = true,
+                    "unsafe" => unsafe_ = true,
+                    "unwrap" => unwrap = true,
+                    "allow_clippy" => allow_clippy = true,
+                    "automatically_derived" => automatically_derived = true,
+                    "as_ref" => as_ref = Some(None),
+                    "as_mut" => as_mut = Some(None),
+                    "unsafe_pin" => unsafe_pin = true,
+                    "must_use_all" => must_use_all = true,
+                    "debug" => debug = true,
+                    "no_guard" => no_guard = true,
+                    "skip_inline" => inline = Some(Inline::Never),
+                    // Likely another expression, e.g. `#[delegate(self.0, self.1)]`.
+                    _ if !input.is_empty() && !input.peek(Token![,]) => {
+                        return Err(syn::Error::new(option.span(), EXPECTED_RECEIVER));
+                    }
+                    _ => return Err(syn::Error::new(option.span(), "unknown option")),
+                }
+            }
+            if borrow_mut && accessors.is_some() {
+                return Err(syn::Error::new(
+                    option.span(),
+                    "`mut` option conflicts with `ref`, `mut` or `owned` accessors",
+                ));
+            }
+            let conflicts = |a: &str, b: &str| name == a && seen.iter().any(|n| n == b);
+            if conflicts("inline", "skip_inline") || conflicts("skip_inline", "inline") {
+                return Err(syn::Error::new(
+                    option.span(),
+                    "`skip_inline` option conflicts with `inline` option",
+                ));
+            }
+            if conflicts("unwrap", "expect") || conflicts("expect", "unwrap") {
+                return Err(syn::Error::new(
+                    option.span(),
+                    "`unwrap` option conflicts with `expect` option",
+                ));
+            }
+            seen.push(name);
+        }
+        Ok(DelegateArgs {
+            receiver,
+            borrow_mut,
+            accessors,
+            ty,
+            wrap,
+            unsafe_,
+            unwrap,
+            expect,
+            inline,
+            on_call,
+            allow_clippy,
+            automatically_derived,
+            where_clause,
+            as_ref,
+            as_mut,
+            variants,
+            unsafe_pin,
+            use_,
+            via,
+            must_use_all,
+            debug,
+            no_guard,
+        })
+    }
+}
+
+impl DelegateArgs {
+    /// Get the value out of an `Option` or `Result` receiver, borrowing it as the method borrows
+    /// `self`.
+    fn unwrap_target(&self, receiver: TokenStream, kind: SelfKind, propagate: bool) -> TokenStream {
+        if !self.unwrap {
+            return receiver;
+        }
+        let receiver = match kind {
+            SelfKind::Ref => quote! { #receiver.as_ref() },
+            SelfKind::Mut => quote! { #receiver.as_mut() },
+            SelfKind::Owned => receiver,
+        };
+        if propagate {
+            return quote! { #receiver? };
+        }
+        match &self.expect {
+            Some(msg) => quote! { #receiver.expect(#msg) },
+            None => quote! { #receiver.unwrap() },
+        }
+    }
+
+    /// Whether the target is already borrowed as `kind` after the conversions, e.g. `as_ref()`.
+    fn borrows_target(&self, kind: SelfKind) -> bool {
+        match kind {
+            SelfKind::Ref => self.unwrap || self.as_ref.is_some(),
+            SelfKind::Mut => self.unwrap || self.as_mut.is_some(),
+            SelfKind::Owned => false,
+        }
+    }
+
+    /// Whether the receiver is converted to the target rather than being the target itself.
+    fn converts(&self, kind: SelfKind) -> bool {
+        self.borrow_mut
+            || self.accessors.is_some()
+            || self.via.is_some()
+            || self.unwrap
+            || matches!(
+                (kind, &self.as_ref, &self.as_mut),
+                (SelfKind::Ref, Some(_), _) | (SelfKind::Mut, _, Some(_))
+            )
+    }
+
+    /// Build the expression the delegated method is called on.
+    fn receiver(&self, kind: SelfKind) -> Result<TokenStream, &'static str> {
+        let receiver = self.access(kind)?;
+        Ok(match (kind, &self.as_ref, &self.as_mut) {
+            (SelfKind::Ref, Some(Some(ty)), _) => {
+                quote! { ::std::convert::AsRef::<#ty>::as_ref(&#receiver) }
+            }
+            (SelfKind::Ref, Some(None), _) => quote! { #receiver.as_ref() },
+            (SelfKind::Mut, _, Some(Some(ty))) => {
+                quote! { ::std::convert::AsMut::<#ty>::as_mut(&mut #receiver) }
+            }
+            (SelfKind::Mut, _, Some(None)) => quote! { #receiver.as_mut() },
+            _ => receiver,
+        })
+    }
+
+    /// Build the expression accessing the target, before any conversion.
+    fn access(&self, kind: SelfKind) -> Result<TokenStream, &'static str> {
+        let target = self.accessor(kind)?;
+        Ok(match &self.via {
+            Some(via) => quote! { #target.#via() },
+            None => target,
+        })
+    }
+
+    /// Apply `borrow_mut()` or the accessor for how the method takes `self`.
+    fn accessor(&self, kind: SelfKind) -> Result<TokenStream, &'static str> {
+        let receiver = &self.receiver;
+        if self.borrow_mut {
+            return Ok(quote! { #receiver.borrow_mut() });
+        }
+        let accessors = match &self.accessors {
+            Some(accessors) => accessors,
+            None => return Ok(receiver.to_token_stream()),
+        };
+        let accessor = match kind {
+            SelfKind::Ref => accessors
+                .ref_
+                .as_ref()
+                .ok_or("no `ref` accessor for `&self`"),
+            SelfKind::Mut => accessors
+                .mut_
+                .as_ref()
+                .ok_or("no `mut` accessor for `&mut self`"),
+            SelfKind::Owned => accessors
+                .owned
+                .as_ref()
+                .ok_or("no `owned` accessor for `self`"),
+        }?;
+        Ok(quote! { #receiver.#accessor() })
+    }
+
+    /// Build the expression wrapping `value` into `Self`.
+    ///
+    /// Unless specified via `wrap`, it's inferred when the receiver is a field of `self`.
+    fn wrap(&self, value: TokenStream) -> Result<TokenStream, &'static str> {
+        if let Some(wrap) = &self.wrap {
+            return Ok(quote! { #wrap(#value) });
+        }
+        match &self.receiver {
+            Expr::Field(ExprField {
+                base,
+                member: Member::Unnamed(_),
+                ..
+            }) if is_self_expr(base) => Ok(quote! { Self(#value) }),
+            Expr::Field(ExprField {
+                base,
+                member: Member::Named(member),
+                ..
+            }) if is_self_expr(base) => Ok(quote! { Self { #member: #value } }),
+            _ => Err("cannot infer how to wrap into Self, specify `wrap = ...` in #[delegate]"),
+        }
+    }
+}
+
+/// How the result of the delegated call is wrapped into `Self`.
+enum WrapSelf {
+    /// `Self`
+    Value,
+    /// `Result<Self, _>`
+    Result,
+    /// `Option<Self>`
+    Option,
+}
+
+impl WrapSelf {
+    fn from_return_type(output: &ReturnType, context: &ImplContext) -> Option<Self> {
+        let ty = match output {
+            ReturnType::Type(_, ty) => ty,
+            ReturnType::Default => return None,
+        };
+        if context.is_self_type(ty) {
+            return Some(WrapSelf::Value);
+        }
+        let segment = match &**ty {
+            Type::Path(TypePath { qself: None, path }) => path.segments.last()?,
+            _ => return None,
+        };
+        let kind = if segment.ident == "Result" {
+            WrapSelf::Result
+        } else if segment.ident == "Option" {
+            WrapSelf::Option
+        } else {
+            return None;
+        };
+        match &segment.arguments {
+            PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(GenericArgument::Type(ty)) if context.is_self_type(ty) => Some(kind),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+fn delegate_input(input: TokenStream, delegate: &DelegateArgs) -> TokenStream {
+    if let Ok(input) = syn::parse2::<ItemImpl>(input.clone()) {
+        return delegate_impl_block(input, delegate);
+    }
+    if let Ok(mut input) = syn::parse2::<ImplItemFn>(input.clone()) {
+        // Whether the method is in a trait impl is unknown here.
+        if let Some(Inline::Inherent) = delegate.inline {
+            let msg = "`inline = inherent` is only allowed on impl blocks";
+            return syn::Error::new(input.sig.fn_token.span, msg).into_compile_error();
+        }
+        if let Some(where_clause) = &delegate.where_clause {
+            let predicates = where_clause.predicates.iter().cloned();
+            input
+                .sig
+                .generics
+                .make_where_clause()
+                .predicates
+                .extend(predicates);
+        }
+        return delegate_fn(input, delegate, &ImplContext::default());
+    }
+    // Methods in a trait definition parse as above when they have a default body.
+    if let Ok(input) = syn::parse2::<TraitItemFn>(input.clone()) {
+        let msg = "expected a default body `{}` for #[delegate]";
+        return quote_spanned! { input.semi_token.span() => compile_error!(#msg); };
+    }
+    // So do consts without a value in an impl block.
+    if let Ok(input) = syn::parse2::<TraitItemConst>(input.clone()) {
+        if let Some((eq_token, _)) = &input.default {
+            let msg = "expected a const without value for #[delegate]";
+            return syn::Error::new(eq_token.span, msg).into_compile_error();
+        }
+        return delegate_const(input, delegate, &ImplContext::default());
+    }
+    let mut tokens = input.into_iter();
+    let first_non_attr_token = 'outer: loop {
+        match tokens.next() {
+            None => break None,
+            Some(TokenTree::Punct(p)) if p.as_char() == '#' => {}
+            Some(token) => break Some(token),
+        }
+        loop {
+            match tokens.next() {
+                None => break 'outer None,
+                Some(TokenTree::Punct(_)) => {}
+                Some(TokenTree::Group(_)) => continue 'outer,
+                Some(token) => break 'outer Some(token),
+            }
+        }
+    };
+    if let Some(token) = first_non_attr_token {
+        let msg = match &token {
+            TokenTree::Ident(ident) if ident == "impl" => "invalid impl block for #[delegate]",
+            TokenTree::Ident(ident) if ident == "fn" => "invalid method for #[delegate]",
+            _ => "expected an impl block or method inside impl block",
+        };
+        quote_spanned! { token.span() => compile_error!(#msg); }
+    } else {
+        panic!("unexpected eof")
+    }
+}
+
+fn delegate_all_input(input: TokenStream, receivers: Vec<Expr>) -> TokenStream {
+    if receivers.is_empty() {
+        let msg = "expected receiver expressions referencing self";
+        return quote! { compile_error!(#msg); };
+    }
+    let delegates = receivers
+        .iter()
+        .map(|receiver| syn::parse2::<DelegateArgs>(receiver.to_token_stream()))
+        .collect::<syn::Result<Vec<_>>>();
+    let delegates = match delegates {
+        Ok(delegates) => delegates,
+        Err(e) => return e.into_compile_error(),
+    };
+    if let Ok(mut input) = syn::parse2::<ItemImpl>(input.clone()) {
+        let mut errors = TokenStream::new();
+        let context = ImplContext::new(&mut input, &mut errors);
+        let items = input.items.into_iter().map(|item| match item {
+            ImplItem::Fn(f) if !f.attrs.iter().any(is_delegate_attr) => {
+                let tokens = delegate_all_fn(f, &delegates, &context);
+                ImplItem::Verbatim(tokens)
+            }
+            _ => item,
+        });
+        input.items = items.collect();
+        return quote! { #errors #input };
+    }
+    if let Ok(input) = syn::parse2::<ImplItemFn>(input.clone()) {
+        let inherent = |delegate: &DelegateArgs| matches!(delegate.inline, Some(Inline::Inherent));
+        if delegates.iter().any(inherent) {
+            let msg = "`inline = inherent` is only allowed on impl blocks";
+            return syn::Error::new(input.sig.fn_token.span, msg).into_compile_error();
+        }
+        return delegate_all_fn(input, &delegates, &ImplContext::default());
+    }
+    let msg = "expected an impl block or method inside impl block";
+    quote! { compile_error!(#msg); }
+}
+
+/// Generate a method calling the target via each of the delegates in order.
+fn delegate_all_fn(
+    input: ImplItemFn,
+    delegates: &[DelegateArgs],
+    context: &ImplContext,
+) -> TokenStream {
+    if let ReturnType::Type(_, ty) = &input.sig.output {
+        let msg = "#[delegate_all] requires methods returning ()";
+        return syn::Error::new(ty.span(), msg).into_compile_error();
+    }
+    let mut stmts = Vec::new();
+    let mut output = None;
+    for delegate in delegates {
+        let tokens = delegate_fn(input.clone(), delegate, context);
+        // Errors are returned as is.
+        let mut func = match syn::parse2::<ImplItemFn>(tokens.clone()) {
+            Ok(func) => func,
+            Err(_) => return tokens,
+        };
+        stmts.append(&mut func.block.stmts);
+        output.get_or_insert(func);
+    }
+    let mut output = output.unwrap();
+    output.block.stmts = stmts;
+    output.into_token_stream()
+}
+
+/// Information from the enclosing `impl` block which affects each method.
+#[derive(Default)]
+struct ImplContext {
+    /// Pairs of inner method name and wrapper method name from `#[rename]`.
+    renames: Vec<(Ident, Ident)>,
+    /// The type the `impl` block is for.
+    self_ty: Option<Type>,
+    /// Visibility from `#[vis]` for methods without their own.
+    vis: Option<Visibility>,
+    /// Whether the `impl` block is a trait impl.
+    is_trait: bool,
+}
+
+impl ImplContext {
+    /// Take `#[rename]` and `#[vis]` out of the attributes of the `impl` block to build its
+    /// context, pushing errors of them into `errors`.
+    fn new(input: &mut ItemImpl, errors: &mut TokenStream) -> Self {
+        let mut context = ImplContext::default();
+        input.attrs.retain(|attr| {
+            if attr.path().is_ident("vis") {
+                if context.vis.is_some() {
+                    let msg = "duplicate #[vis] attribute";
+                    errors.extend(syn::Error::new(attr.span(), msg).into_compile_error());
+                }
+                match attr.parse_args::<Visibility>() {
+                    Ok(vis) => context.vis = Some(vis),
+                    Err(e) => errors.extend(e.into_compile_error()),
+                }
+                return false;
+            }
+            if !attr.path().is_ident("rename") {
+                return true;
+            }
+            let renames = attr.parse_args_with(Punctuated::<Rename, Token![,]>::parse_terminated);
+            match renames {
+                Ok(renames) => {
+                    for Rename { inner, wrapper } in renames {
+                        if context.renames.iter().any(|(i, _)| *i == inner) {
+                            let msg = format!("duplicate rename of `{}`", inner);
+                            errors.extend(syn::Error::new(inner.span(), msg).into_compile_error());
+                        } else {
+                            context.renames.push((inner, wrapper));
+                        }
+                    }
+                }
+                Err(e) => errors.extend(e.into_compile_error()),
+            }
+            false
+        });
+        for (_, wrapper) in &context.renames {
+            let matches =
+                |item: &ImplItem| matches!(item, ImplItem::Fn(f) if f.sig.ident == *wrapper);
+            if !input.items.iter().any(matches) {
+                let msg = format!("no method named `{}` in the impl block to rename", wrapper);
+                errors.extend(syn::Error::new(wrapper.span(), msg).into_compile_error());
+            }
+        }
+        context.self_ty = Some((*input.self_ty).clone());
+        context.is_trait = input.trait_.is_some();
+        if let Some(vis) = context.vis.as_ref().filter(|_| context.is_trait) {
+            let msg = "#[vis] is only allowed on inherent impls";
+            errors.extend(syn::Error::new(vis.span(), msg).into_compile_error());
+            context.vis = None;
+        }
+        context
+    }
+
+    /// Check whether the given type is `Self`, either literally or spelled as the `impl` type.
+    fn is_self_type(&self, ty: &Type) -> bool {
+        if is_self_type(ty) {
+            return true;
+        }
+        let to_string = |ty: &Type| ty.to_token_stream().to_string();
+        self.self_ty.as_ref().map(to_string) == Some(to_string(ty))
+    }
+
+    fn rename(&self, name: &Ident) -> Option<&Ident> {
+        self.renames
+            .iter()
+            .find(|(_, wrapper)| wrapper == name)
+            .map(|(inner, _)| inner)
+    }
+}
 
-#[proc_macro_attribute]
-pub fn delegate(attr: RawTokenStream, item: RawTokenStream) -> RawTokenStream {
-    let receiver = parse_macro_input!(attr as Expr);
-    delegate_input(item.into(), &receiver).into()
+/// A single `name = expr` entry in `#[bind]`.
+struct Bind {
+    name: Ident,
+    expr: Expr,
 }
 
-fn delegate_input(input: TokenStream, receiver: &Expr) -> TokenStream {
-    if let Ok(input) = syn::parse2::<ItemImpl>(input.clone()) {
-        return delegate_impl_block(input, receiver);
-    }
-    if let Ok(input) = syn::parse2::<ImplItemFn>(input.clone()) {
-        return delegate_fn(input, receiver);
+impl Parse for Bind {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let expr = input.parse()?;
+        Ok(Bind { name, expr })
     }
-    let mut tokens = input.into_iter();
-    let first_non_attr_token = 'outer: loop {
-        match tokens.next() {
-            None => break None,
-            Some(TokenTree::Punct(p)) if p.as_char() == '#' => {}
-            Some(token) => break Some(token),
-        }
-        loop {
-            match tokens.next() {
-                None => break 'outer None,
-                Some(TokenTree::Punct(_)) => {}
-                Some(TokenTree::Group(_)) => continue 'outer,
-                Some(token) => break 'outer Some(token),
-            }
-        }
-    };
-    if let Some(token) = first_non_attr_token {
-        let msg = match &token {
-            TokenTree::Ident(ident) if ident == "impl" => "invalid impl block for #[delegate]",
-            TokenTree::Ident(ident) if ident == "fn" => "invalid method for #[delegate]",
-            _ => "expected an impl block or method inside impl block",
-        };
-        quote_spanned! { token.span() => compile_error!(#msg); }
-    } else {
-        panic!("unexpected eof")
+}
+
+/// A single `inner = wrapper` entry in `#[rename]`.
+struct Rename {
+    inner: Ident,
+    wrapper: Ident,
+}
+
+impl Parse for Rename {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let inner = input.call(Ident::parse_any)?;
+        input.parse::<Token![=]>()?;
+        let wrapper = input.call(Ident::parse_any)?;
+        Ok(Rename { inner, wrapper })
     }
 }
 
-fn delegate_impl_block(input: ItemImpl, receiver: &Expr) -> TokenStream {
+fn delegate_impl_block(mut input: ItemImpl, delegate: &DelegateArgs) -> TokenStream {
+    let mut errors = TokenStream::new();
+    let context = ImplContext::new(&mut input, &mut errors);
     let ItemImpl {
-        attrs,
+        mut attrs,
         defaultness,
         unsafety,
         impl_token,
@@ -174,29 +1557,176 @@ fn delegate_impl_block(input: ItemImpl, receiver: &Expr) -> TokenStream {
         brace_token: _,
         items,
     } = input;
+    if delegate.automatically_derived {
+        if trait_.is_none() {
+            let msg = "`automatically_derived` option requires a trait impl";
+            errors.extend(syn::Error::new(impl_token.span, msg).into_compile_error());
+        } else if !attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("automatically_derived"))
+        {
+            attrs.push(syn::parse_quote!(#[automatically_derived]));
+        }
+    }
+    if let Some(where_clause) = &delegate.where_clause {
+        let predicates = where_clause.predicates.iter().cloned();
+        generics.make_where_clause().predicates.extend(predicates);
+    }
     let where_clause = generics.where_clause.take();
+    let trait_path = trait_.as_ref().map(|(_, path, _)| path.clone());
     let trait_ = trait_.map(|(bang, path, for_)| quote!(#bang #path #for_));
     let items = items.into_iter().map(|item| {
         let func = match item {
-            ImplItem::Fn(f) => f,
+            // Methods with their own `#[delegate]` are left for that attribute to expand.
+            ImplItem::Fn(f) if !f.attrs.iter().any(is_delegate_attr) => f,
+            // Consts and types without a value are parsed as verbatim tokens.
+            ImplItem::Verbatim(tokens) => {
+                if let Ok(item) = syn::parse2::<TraitItemConst>(tokens.clone()) {
+                    if item.default.is_none() && !item.attrs.iter().any(is_delegate_attr) {
+                        return delegate_const(item, delegate, &context);
+                    }
+                }
+                match (syn::parse2::<TraitItemType>(tokens.clone()), &trait_path) {
+                    (Ok(item), Some(path)) if item.default.is_none() => {
+                        return delegate_type(item, path, delegate);
+                    }
+                    _ => return tokens,
+                }
+            }
             _ => return item.into_token_stream(),
         };
-        delegate_fn(func, receiver)
+        delegate_fn(func, delegate, &context)
     });
 
     quote! {
+        #errors
         #(#attrs)* #defaultness #unsafety #impl_token #generics #trait_ #self_ty #where_clause {
             #(#items)*
         }
     }
 }
 
-fn delegate_fn(input: ImplItemFn, receiver: &Expr) -> TokenStream {
+fn delegate_const(
+    item: TraitItemConst,
+    delegate: &DelegateArgs,
+    context: &ImplContext,
+) -> TokenStream {
+    let TraitItemConst {
+        attrs,
+        const_token,
+        ident,
+        generics,
+        colon_token,
+        ty,
+        default: _,
+        semi_token,
+    } = item;
+    let target = match &delegate.ty {
+        Some(target) => target,
+        None => {
+            let msg = "delegating a const requires `ty = ...` in #[delegate]";
+            return syn::Error::new(ident.span(), msg).into_compile_error();
+        }
+    };
+    let value = quote! { <#target>::#ident };
+    // Build a value of `Self` with the const of the target.
+    let value = if context.is_self_type(&ty) {
+        match delegate.wrap(value) {
+            Ok(value) => value,
+            Err(msg) => return syn::Error::new(ty.span(), msg).into_compile_error(),
+        }
+    } else {
+        value
+    };
+    quote! {
+        #(#attrs)* #const_token #ident #generics #colon_token #ty = #value #semi_token
+    }
+}
+
+/// Generate an associated type of a trait impl as the same type of the target.
+fn delegate_type(item: TraitItemType, trait_path: &Path, delegate: &DelegateArgs) -> TokenStream {
+    let TraitItemType {
+        attrs,
+        type_token,
+        ident,
+        generics,
+        semi_token,
+        ..
+    } = item;
+    let target = match &delegate.ty {
+        Some(target) => target,
+        None => {
+            let msg = "delegating an associated type requires `ty = ...` in #[delegate]";
+            return syn::Error::new(ident.span(), msg).into_compile_error();
+        }
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        #(#attrs)* #type_token #ident #impl_generics =
+            <#target as #trait_path>::#ident #ty_generics #where_clause #semi_token
+    }
+}
+
+/// Attributes on methods which are interpreted by us.
+const MARKERS: &[&str] = &[
+    "inline",
+    "into",
+    "no_docs",
+    "inline_always",
+    "no_inline",
+    "construct",
+    "collect",
+    "propagate",
+    "allow_missing_self",
+    "to",
+    "call",
+    "bind",
+    "discard",
+    "into_args",
+    "to_owned",
+    "call_as_fn",
+    "call_args",
+    "take",
+];
+
+/// Pairs of attributes which can't be used together.
+const CONFLICTS: &[(&str, &str)] = &[
+    ("into", "construct"),
+    ("inline", "inline_always"),
+    ("inline", "no_inline"),
+    ("inline_always", "no_inline"),
+    ("construct", "collect"),
+    ("construct", "propagate"),
+    ("construct", "allow_missing_self"),
+    ("construct", "to"),
+    ("collect", "to_owned"),
+    ("discard", "to_owned"),
+    ("call", "call_as_fn"),
+    ("construct", "call_as_fn"),
+    ("bind", "call_args"),
+    ("call", "take"),
+    ("call_as_fn", "take"),
+    ("construct", "take"),
+];
+
+/// Methods of the receiver returning a guard, which references into the target can't outlive.
+const GUARDS: &[&str] = &["borrow", "borrow_mut", "lock", "read", "write"];
+
+/// Clippy lints allowed by the `allow_clippy` option. Signatures often mirror the target, e.g.
+/// an inherent `next` of an iterator wrapper, and `inline_always` fires with `inline = always`.
+const CLIPPY_LINTS: &[&str] = &[
+    "inline_always",
+    "needless_lifetimes",
+    "should_implement_trait",
+    "wrong_self_convention",
+];
+
+fn delegate_fn(input: ImplItemFn, delegate: &DelegateArgs, context: &ImplContext) -> TokenStream {
     let ImplItemFn {
         mut attrs,
-        vis,
+        mut vis,
         defaultness,
-        sig,
+        mut sig,
         block: _,
     } = input;
     let mut errors = TokenStream::new();
@@ -210,17 +1740,77 @@ fn delegate_fn(input: ImplItemFn, receiver: &Expr) -> TokenStream {
     }
     // Parse attributes.
     let mut has_inline = false;
+    let mut has_cold = false;
+    let mut cfg_inline = Vec::new();
+    let mut has_inline_always = false;
+    let mut has_no_inline = false;
     let mut has_into = false;
+    let mut into_ty = None;
+    let mut has_no_docs = false;
+    let mut has_construct = false;
+    let mut has_collect = false;
+    let mut has_propagate = false;
+    let mut has_allow_missing_self = false;
+    let mut has_discard = false;
+    let mut has_into_args = false;
+    let mut has_to_owned = false;
+    let mut has_call_as_fn = false;
+    let mut has_take = false;
     let mut call_name = None;
+    let mut call_path = None;
+    let mut to = None;
+    let mut binds = Vec::<Bind>::new();
+    let mut call_args = None;
+    // Attributes consumed by us, with their spans, to check for conflicts.
+    let mut present = Vec::new();
     attrs.retain(|attr| {
         let path = attr.path();
+        if let Some(name) = MARKERS.iter().find(|name| path.is_ident(name)) {
+            present.push((*name, attr.span()));
+        }
+        // Parse a marker attribute which takes no argument.
+        macro_rules! marker {
+            ($flag: ident, $name: literal) => {{
+                match &attr.meta {
+                    Meta::List(meta) => {
+                        push_error!(meta.delimiter.span().join(), "unexpected argument")
+                    }
+                    Meta::NameValue(meta) => {
+                        push_error!(meta.eq_token.span, "unexpected argument")
+                    }
+                    Meta::Path(_) => {}
+                }
+                if $flag {
+                    push_error!(attr.span(), concat!("duplicate #[", $name, "] attribute"));
+                }
+                $flag = true;
+                return false;
+            }};
+        }
         if path.is_ident("inline") {
             has_inline = true;
+        } else if path.is_ident("cold") {
+            has_cold = true;
+        } else if path.is_ident("cfg_attr") {
+            // Remember conditions under which an inline attribute is specified.
+            let parsed = attr.parse_args_with(|input: ParseStream| {
+                let predicate = input.parse::<Meta>()?;
+                input.parse::<Token![,]>()?;
+                let attrs = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+                Ok((predicate, attrs))
+            });
+            if let Ok((predicate, attrs)) = parsed {
+                if attrs.iter().any(|attr| attr.path().is_ident("inline")) {
+                    cfg_inline.push(predicate);
+                }
+            }
         } else if path.is_ident("into") {
+            // Like a marker, but the type of the result can be specified.
             match &attr.meta {
-                Meta::List(meta) => {
-                    push_error!(meta.delimiter.span().join(), "unexpected argument")
-                }
+                Meta::List(_) => match attr.parse_args::<Type>() {
+                    Ok(ty) => into_ty = Some(ty),
+                    Err(e) => push_error!(e),
+                },
                 Meta::NameValue(meta) => push_error!(meta.eq_token.span, "unexpected argument"),
                 Meta::Path(_) => {}
             }
@@ -229,47 +1819,484 @@ fn delegate_fn(input: ImplItemFn, receiver: &Expr) -> TokenStream {
             }
             has_into = true;
             return false;
-        } else if path.is_ident("call") {
-            match attr.parse_args::<Ident>() {
-                Ok(ident) => {
-                    if call_name.is_some() {
-                        push_error!(attr.span(), "duplicate #[call] attribute");
+        } else if path.is_ident("no_docs") {
+            marker!(has_no_docs, "no_docs");
+        } else if path.is_ident("inline_always") {
+            marker!(has_inline_always, "inline_always");
+        } else if path.is_ident("no_inline") {
+            marker!(has_no_inline, "no_inline");
+        } else if path.is_ident("construct") {
+            marker!(has_construct, "construct");
+        } else if path.is_ident("collect") {
+            marker!(has_collect, "collect");
+        } else if path.is_ident("propagate") {
+            marker!(has_propagate, "propagate");
+        } else if path.is_ident("allow_missing_self") {
+            marker!(has_allow_missing_self, "allow_missing_self");
+        } else if path.is_ident("discard") {
+            marker!(has_discard, "discard");
+        } else if path.is_ident("into_args") {
+            marker!(has_into_args, "into_args");
+        } else if path.is_ident("to_owned") {
+            marker!(has_to_owned, "to_owned");
+        } else if path.is_ident("call_as_fn") {
+            marker!(has_call_as_fn, "call_as_fn");
+        } else if path.is_ident("take") {
+            marker!(has_take, "take");
+        } else if path.is_ident("to") {
+            if to.is_some() {
+                push_error!(attr.span(), "duplicate #[to] attribute");
+            }
+            match attr.parse_args::<Expr>() {
+                Ok(receiver) => to = Some(parenthesize(receiver)),
+                Err(e) => push_error!(e),
+            }
+            return false;
+        } else if path.is_ident("bind") {
+            match attr.parse_args_with(Punctuated::<Bind, Token![,]>::parse_terminated) {
+                Ok(list) => {
+                    for bind in list {
+                        if binds.iter().any(|b| b.name == bind.name) {
+                            let msg = format!("duplicate binding of `{}`", bind.name);
+                            push_error!(bind.name.span(), msg);
+                        } else {
+                            binds.push(bind);
+                        }
                     }
-                    call_name = Some(ident);
                 }
                 Err(e) => push_error!(e),
             }
             return false;
+        } else if path.is_ident("call_args") {
+            if call_args.is_some() {
+                push_error!(attr.span(), "duplicate #[call_args] attribute");
+            }
+            match attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+                Ok(list) => call_args = Some(list),
+                Err(e) => push_error!(e),
+            }
+            return false;
+        } else if path.is_ident("call") {
+            if call_name.is_some() || call_path.is_some() {
+                push_error!(attr.span(), "duplicate #[call] attribute");
+            }
+            match attr.parse_args::<Ident>() {
+                Ok(ident) => call_name = Some(ident),
+                // A path like `<Inner as Trait>::method` is called with the receiver as the
+                // first argument.
+                Err(_)
+                    if attr
+                        .parse_args::<ExprPath>()
+                        .is_ok_and(|path| path.qself.is_some() || path.path.segments.len() > 1) =>
+                {
+                    call_path = attr.parse_args::<ExprPath>().ok();
+                }
+                // Suggest the raw identifier if a keyword is given.
+                Err(e) => match attr.parse_args_with(Ident::parse_any) {
+                    Ok(keyword)
+                        if !matches!(
+                            &*keyword.to_string(),
+                            "self" | "Self" | "super" | "crate"
+                        ) =>
+                    {
+                        let msg = format!(
+                            "expected identifier, found keyword `{0}`, use `r#{0}` instead",
+                            keyword
+                        );
+                        push_error!(keyword.span(), msg);
+                    }
+                    _ => push_error!(e),
+                },
+            }
+            return false;
         }
         true
     });
-    // Mark method always inline if it's not otherwise specified.
-    let inline = if !has_inline {
-        quote!(#[inline(always)])
+    for (a, b) in CONFLICTS {
+        let find = |name| present.iter().find(|(n, _)| n == name);
+        if let (Some(_), Some((_, span))) = (find(a), find(b)) {
+            push_error!(*span, format!("#[{}] conflicts with #[{}]", b, a));
+        }
+    }
+    // The return type, or the type given to `#[into]`, decides what to collect into.
+    if has_collect && into_ty.is_none() {
+        match &sig.output {
+            ReturnType::Type(_, ty) if !matches!(**ty, Type::ImplTrait(_)) => {}
+            _ => push_error!(
+                sig.ident.span(),
+                "#[collect] requires a concrete return type"
+            ),
+        }
+    }
+    // The unwrapped receiver is propagated via `?`, so the result needs wrapping.
+    let mut propagate = None;
+    if has_propagate {
+        if !delegate.unwrap {
+            push_error!(
+                sig.ident.span(),
+                "#[propagate] requires `unwrap` in #[delegate]"
+            );
+        }
+        propagate = match &sig.output {
+            ReturnType::Type(_, ty) => match &**ty {
+                Type::Path(TypePath { qself: None, path }) => {
+                    path.segments
+                        .last()
+                        .and_then(|segment| match &*segment.ident.to_string() {
+                            "Option" => Some(quote! { ::std::option::Option::Some }),
+                            "Result" => Some(quote! { ::std::result::Result::Ok }),
+                            _ => None,
+                        })
+                }
+                _ => None,
+            },
+            ReturnType::Default => None,
+        };
+        if propagate.is_none() {
+            let msg = "#[propagate] method must return Option<_> or Result<_, _>";
+            match &sig.output {
+                ReturnType::Type(_, ty) => push_error!(ty.span(), msg),
+                ReturnType::Default => push_error!(sig.ident.span(), msg),
+            }
+        }
+    }
+    if has_discard {
+        if let ReturnType::Type(_, ty) = &sig.output {
+            push_error!(ty.span(), "#[discard] method must return ()");
+        }
+    }
+    // Replace the receiver, keeping other options.
+    let overridden;
+    let delegate = match to {
+        Some(receiver) => {
+            overridden = DelegateArgs {
+                receiver,
+                variants: None,
+                ..delegate.clone()
+            };
+            &overridden
+        }
+        None => delegate,
+    };
+    // Keep `#[must_use]` given on the method, which may have a reason.
+    if delegate.must_use_all
+        && !matches!(sig.output, ReturnType::Default)
+        && !attrs.iter().any(|attr| attr.path().is_ident("must_use"))
+    {
+        attrs.push(syn::parse_quote!(#[must_use]));
+    }
+    if let (Visibility::Inherited, Some(block_vis)) = (&vis, &context.vis) {
+        vis = block_vis.clone();
+    }
+    // Strip doc comments, but keep other doc attributes like `#[doc(hidden)]`.
+    if has_no_docs {
+        attrs.retain(|attr| {
+            !(attr.path().is_ident("doc") && matches!(attr.meta, Meta::NameValue(_)))
+        });
+    }
+    // Add inline attribute if it's not otherwise specified.
+    let inline = if has_inline {
+        Inline::Never
+    } else if has_inline_always {
+        Inline::Always
+    } else if has_no_inline || has_cold {
+        Inline::Never
     } else {
-        quote!()
+        let inline = delegate
+            .inline
+            .map(|inline| inline.for_impl(context.is_trait));
+        inline.unwrap_or_else(Inline::default_policy)
     };
-    let mut inputs = sig.inputs.iter();
-    // Extract the self token.
-    let self_token = match inputs.next() {
-        Some(FnArg::Receiver(receiver)) => receiver.self_token.to_token_stream(),
-        Some(FnArg::Typed(pat)) => match &*pat.pat {
-            Pat::Ident(ident) if ident.ident == "self" => ident.ident.to_token_stream(),
-            _ => {
-                push_error!(pat.span(), "expected self");
-                TokenStream::new()
+    // Only add it when no `#[cfg_attr(..., inline)]` applies.
+    let inline = match inline.meta() {
+        Some(meta) if !cfg_inline.is_empty() => {
+            quote! { #[cfg_attr(not(any(#(#cfg_inline),*)), #meta)] }
+        }
+        _ => inline.to_token_stream(),
+    };
+    // Strip `#[by_ref]` and `#[by_mut]` from arguments, which are passed borrowed, and `#[skip]`
+    // from arguments which aren't passed, e.g. when used by `#[to]`.
+    let mut borrowed = Vec::new();
+    let mut skipped = Vec::new();
+    for arg in sig.inputs.iter_mut() {
+        let pat = match arg {
+            FnArg::Typed(pat) => pat,
+            FnArg::Receiver(_) => continue,
+        };
+        let mut borrow = None;
+        let mut skip = false;
+        pat.attrs.retain(|attr| {
+            if attr.path().is_ident("skip") {
+                if let Err(e) = attr.meta.require_path_only() {
+                    push_error!(e);
+                }
+                if skip {
+                    push_error!(attr.span(), "duplicate #[skip] attribute");
+                }
+                skip = true;
+                return false;
+            }
+            let (name, tokens) = if attr.path().is_ident("by_ref") {
+                ("by_ref", quote! { & })
+            } else if attr.path().is_ident("by_mut") {
+                ("by_mut", quote! { &mut })
+            } else {
+                return true;
+            };
+            if let Err(e) = attr.meta.require_path_only() {
+                push_error!(e);
+            }
+            match borrow {
+                Some((prev, _)) if prev == name => {
+                    push_error!(attr.span(), format!("duplicate #[{}] attribute", name));
+                }
+                Some((prev, _)) => {
+                    let msg = format!("#[{}] conflicts with #[{}]", name, prev);
+                    push_error!(attr.span(), msg);
+                }
+                None => {}
             }
+            borrow = Some((name, tokens));
+            false
+        });
+        if let (Some((name, _)), true) = (&borrow, skip) {
+            let msg = format!("#[skip] conflicts with #[{}]", name);
+            push_error!(pat.span(), msg);
+        }
+        if let (Some((_, borrow)), Pat::Ident(ident)) = (borrow, &*pat.pat) {
+            borrowed.push((ident.ident.clone(), borrow));
+        }
+        if let (true, Pat::Ident(ident)) = (skip, &*pat.pat) {
+            skipped.push(ident.ident.clone());
+        }
+    }
+    let mut inputs = sig.inputs.iter().peekable();
+    // Extract the self token, unless the method constructs a new value.
+    let mut self_receiver = None;
+    let mut wrap_self = None;
+    // Return the wrapper itself for methods returning `&Self` or `&mut Self`, e.g. builders.
+    // With arguments of `Self`, the result may be one of them, e.g. `max`, so leave it as is.
+    let has_self_args = sig.inputs.iter().any(|arg| match arg {
+        FnArg::Typed(pat) => match &*pat.ty {
+            Type::Reference(ty) => context.is_self_type(&ty.elem),
+            ty => context.is_self_type(ty),
         },
-        None => {
-            push_error!(sig.paren_token.span.join(), "expected self");
+        FnArg::Receiver(_) => false,
+    });
+    let returns_self_ref = !has_into
+        && !has_self_args
+        && sig.receiver().is_some()
+        && matches!(&sig.output, ReturnType::Type(_, ty)
+            if matches!(&**ty, Type::Reference(ty) if context.is_self_type(&ty.elem)));
+    // Methods without self which return `Self` construct a new value when the type of the
+    // target is known.
+    let has_construct = has_construct
+        || (delegate.ty.is_some()
+            && !matches!(inputs.peek(), Some(FnArg::Receiver(_)))
+            && WrapSelf::from_return_type(&sig.output, context).is_some());
+    if has_construct {
+        if let Some(FnArg::Receiver(receiver)) = inputs.peek() {
+            push_error!(receiver.span(), "unexpected self in #[construct] method");
+            inputs.next();
+        }
+        if delegate.ty.is_none() {
+            push_error!(
+                sig.ident.span(),
+                "#[construct] requires `ty = ...` in #[delegate]"
+            );
+        }
+        if let Err(msg) = delegate.wrap(TokenStream::new()) {
+            push_error!(sig.ident.span(), msg);
+        }
+        wrap_self = WrapSelf::from_return_type(&sig.output, context);
+        if wrap_self.is_none() {
+            let msg = "#[construct] method must return Self, Result<Self, _> or Option<Self>";
+            match &sig.output {
+                ReturnType::Type(_, ty) => push_error!(ty.span(), msg),
+                ReturnType::Default => push_error!(sig.ident.span(), msg),
+            }
+        }
+    } else if has_allow_missing_self && !inputs.peek().is_some_and(|arg| is_self_arg(arg)) {
+        // Called with the arguments only, via the path in `#[call]`, the receiver if it doesn't
+        // reference `self`, e.g. a static, or the type of the target.
+        let receiver = delegate.receiver.to_token_stream();
+        let has_self = delegate.variants.is_some()
+            || mentions(receiver.clone(), &Ident::new("self", Span::call_site()));
+        if call_path.is_none() && !has_self {
+            self_receiver = Some((receiver, TokenStream::new(), SelfKind::Owned));
+        } else if call_path.is_none() && delegate.ty.is_none() {
+            push_error!(
+                sig.ident.span(),
+                "#[allow_missing_self] requires `ty = ...` in #[delegate], a path in #[call], \
+                 or a receiver without self"
+            );
+        }
+    } else {
+        let mut pin = None;
+        let (self_token, self_kind) = match inputs.next() {
+            Some(FnArg::Receiver(receiver)) => {
+                pin = pin_kind(&receiver.ty, context);
+                let kind = pin.unwrap_or_else(|| self_kind(&receiver.ty));
+                (receiver.self_token.to_token_stream(), kind)
+            }
+            Some(FnArg::Typed(pat)) => match &*pat.pat {
+                Pat::Ident(ident) if ident.ident == "self" => {
+                    (ident.ident.to_token_stream(), self_kind(&pat.ty))
+                }
+                _ => {
+                    push_error!(pat.span(), "expected self");
+                    (TokenStream::new(), SelfKind::Owned)
+                }
+            },
+            None => {
+                push_error!(sig.paren_token.span.join(), "expected self");
+                (TokenStream::new(), SelfKind::Owned)
+            }
+        };
+        let receiver = delegate.receiver(self_kind).unwrap_or_else(|msg| {
+            push_error!(sig.inputs.span(), msg);
             TokenStream::new()
+        });
+        // A returned reference would borrow from the guard, which is a temporary of the body.
+        // Elided lifetimes in the return type are those of `self` when it's borrowed.
+        let self_lifetime = match sig.receiver().map(|receiver| &*receiver.ty) {
+            Some(Type::Reference(ty)) => Some(ty.lifetime.as_ref()),
+            _ => None,
+        };
+        match (&sig.output, self_lifetime) {
+            (ReturnType::Type(_, ty), Some(lifetime))
+                if !delegate.no_guard && !returns_self_ref && borrows_self(ty, lifetime) =>
+            {
+                let guard = syn::parse2::<Expr>(receiver.clone())
+                    .ok()
+                    .and_then(|expr| guard_method(&expr).cloned());
+                if let Some(guard) = guard {
+                    let msg = format!(
+                        "returned reference can't outlive the guard from `{}()` in the receiver, \
+                         consider returning a guard mapped via e.g. `Ref::map` instead",
+                        guard
+                    );
+                    push_error!(ty.span(), msg);
+                }
+            }
+            _ => {}
         }
-    };
+        let receiver = delegate.unwrap_target(receiver, self_kind, has_propagate);
+        // Project the pinned `self` to the target, and pin it again.
+        let (receiver, self_kind) = match pin {
+            Some(kind) if delegate.unsafe_pin => {
+                let this = Ident::new("this", Span::mixed_site()).into_token_stream();
+                let target = replace_self(receiver, &this);
+                let receiver = match kind {
+                    SelfKind::Mut => quote! {
+                        ::std::pin::Pin::map_unchecked_mut(#self_token, |#this| &mut #target)
+                    },
+                    _ => quote! { ::std::pin::Pin::map_unchecked(#self_token, |#this| &#target) },
+                };
+                (quote! { unsafe { #receiver } }, SelfKind::Owned)
+            }
+            Some(kind) => {
+                let receiver = match kind {
+                    SelfKind::Mut => {
+                        let this = quote! { ::std::pin::Pin::get_mut(#self_token) };
+                        let target = replace_self(receiver, &this);
+                        quote! { ::std::pin::Pin::new(&mut #target) }
+                    }
+                    _ => {
+                        let this = quote! { ::std::pin::Pin::get_ref(#self_token) };
+                        let target = replace_self(receiver, &this);
+                        quote! { ::std::pin::Pin::new(&#target) }
+                    }
+                };
+                (receiver, SelfKind::Owned)
+            }
+            None => (receiver, self_kind),
+        };
+        // Access the target inside an `unsafe` block, keeping the call itself outside. A bare
+        // place is borrowed inside the block as the method borrows `self`.
+        let (receiver, self_kind) = if !delegate.unsafe_ || (pin.is_some() && delegate.unsafe_pin) {
+            (receiver, self_kind)
+        } else if pin.is_some() || delegate.converts(self_kind) {
+            (quote! { (unsafe { #receiver }) }, self_kind)
+        } else {
+            let receiver = match self_kind {
+                SelfKind::Ref => quote! { (unsafe { &#receiver }) },
+                SelfKind::Mut => quote! { (unsafe { &mut #receiver }) },
+                SelfKind::Owned => quote! { (unsafe { #receiver }) },
+            };
+            (receiver, SelfKind::Owned)
+        };
+        self_receiver = Some((receiver, self_token, self_kind));
+        // Wrap the result if the method returns `Self`, unless it's converted via `#[into]`.
+        if let ReturnType::Type(_, ty) = &sig.output {
+            if !has_into && context.is_self_type(ty) {
+                if let Err(msg) = delegate.wrap(TokenStream::new()) {
+                    push_error!(ty.span(), msg);
+                }
+                wrap_self = Some(WrapSelf::Value);
+            }
+        }
+    }
+    if has_call_as_fn && self_receiver.is_none() {
+        push_error!(
+            sig.ident.span(),
+            "#[call_as_fn] requires a receiver to call"
+        );
+    }
+    let takes_mut = matches!(sig.receiver(), Some(receiver) if pin_kind(&receiver.ty, context)
+        .is_none() && matches!(self_kind(&receiver.ty), SelfKind::Mut));
+    if has_take && (self_receiver.is_none() || !takes_mut) {
+        push_error!(sig.ident.span(), "#[take] method must take `&mut self`");
+    }
     // List all parameters.
     let args = inputs
         .filter_map(|arg| match arg {
             FnArg::Typed(pat) => match &*pat.pat {
-                Pat::Ident(ident) => Some(ident.to_token_stream()),
+                Pat::Ident(ident) if skipped.contains(&ident.ident) => None,
+                Pat::Ident(ident) => {
+                    // Project arguments of `Self` to the target the same way as `self`.
+                    let kind = match &*pat.ty {
+                        Type::Reference(ty) if context.is_self_type(&ty.elem) => self_kind(&pat.ty),
+                        ty if context.is_self_type(ty) => SelfKind::Owned,
+                        _ => {
+                            let borrow = borrowed.iter().find(|(name, _)| *name == ident.ident);
+                            return Some(match borrow {
+                                Some((name, borrow)) => quote! { #borrow #name },
+                                None => ident.to_token_stream(),
+                            });
+                        }
+                    };
+                    if delegate.variants.is_some() {
+                        let msg = "`Self` arguments are not supported with `enum`";
+                        push_error!(pat.ty.span(), msg);
+                        return None;
+                    }
+                    let target = match delegate.receiver(kind) {
+                        Ok(target) => replace_self(target, &ident.ident.to_token_stream()),
+                        Err(msg) => {
+                            push_error!(pat.ty.span(), msg);
+                            return None;
+                        }
+                    };
+                    let target = delegate.unwrap_target(target, kind, has_propagate);
+                    // Access the target inside an `unsafe` block like `self`.
+                    let in_unsafe = |target: TokenStream| match delegate.unsafe_ {
+                        true => quote! { (unsafe { #target }) },
+                        false => target,
+                    };
+                    let borrow = |target: TokenStream| match kind {
+                        SelfKind::Ref => quote! { &#target },
+                        SelfKind::Mut => quote! { &mut #target },
+                        SelfKind::Owned => target,
+                    };
+                    Some(if delegate.borrows_target(kind) {
+                        in_unsafe(target)
+                    } else if delegate.unsafe_ && !delegate.converts(kind) {
+                        in_unsafe(borrow(target))
+                    } else {
+                        borrow(in_unsafe(target))
+                    })
+                }
                 _ => {
                     push_error!(pat.pat.span(), "expect an identifier");
                     None
@@ -281,6 +2308,42 @@ fn delegate_fn(input: ImplItemFn, receiver: &Expr) -> TokenStream {
             }
         })
         .collect::<Vec<_>>();
+    // Pass bound arguments first, resolving `self` in them like in the receiver.
+    let self_token = match sig.receiver() {
+        Some(receiver) => receiver.self_token.to_token_stream(),
+        None => quote! { self },
+    };
+    let mut bound = Vec::new();
+    for Bind { name, expr } in &binds {
+        let clash = sig.inputs.iter().any(|arg| match arg {
+            FnArg::Typed(pat) => matches!(&*pat.pat, Pat::Ident(ident) if ident.ident == *name),
+            FnArg::Receiver(_) => false,
+        });
+        if clash {
+            let msg = format!("binding `{}` conflicts with an argument", name);
+            push_error!(name.span(), msg);
+        }
+        bound.push(replace_self(expr.to_token_stream(), &self_token));
+    }
+    // Arguments given by `#[call_args]` replace those of the method.
+    let args = match &call_args {
+        Some(list) => list
+            .iter()
+            .map(|expr| replace_self(expr.to_token_stream(), &self_token))
+            .collect(),
+        None => args,
+    };
+    let args = args.into_iter().map(|arg| {
+        if has_into_args {
+            quote! { ::std::convert::Into::into(#arg) }
+        } else {
+            arg
+        }
+    });
+    let args = bound.into_iter().chain(args).collect::<Vec<_>>();
+    if has_take && !args.is_empty() {
+        push_error!(sig.inputs.span(), "#[take] method can't have arguments");
+    }
     // Return errors if any.
     if !errors.is_empty() {
         return errors;
@@ -289,25 +2352,360 @@ fn delegate_fn(input: ImplItemFn, receiver: &Expr) -> TokenStream {
         drop(errors);
     }
     // Generate method call.
-    let name = call_name.as_ref().unwrap_or(&sig.ident);
-    // Replace the self token in the receiver with the token we extract above to ensure it comes
-    // from the right hygiene context.
-    let receiver = replace_self(receiver.to_token_stream(), &self_token);
-    let body = quote! { #receiver.#name(#(#args),*) };
+    let name = call_name
+        .as_ref()
+        .or_else(|| context.rename(&sig.ident))
+        .unwrap_or(&sig.ident);
+    // Forward type and const parameters of the method, which can't always be inferred. Type
+    // parameters used by arguments, e.g. closures, are left to inference, as are parameters
+    // determined by bounds of those, and the turbofish is omitted when nothing is left to
+    // specify.
+    let mut inferred = sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat) => Some(pat.ty.to_token_stream()),
+            FnArg::Receiver(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let mut inferred_params = Vec::new();
+    loop {
+        let param = sig.generics.type_params().find(|param| {
+            !inferred_params.contains(&&param.ident)
+                && inferred.iter().any(|ty| mentions(ty.clone(), &param.ident))
+        });
+        let param = match param {
+            Some(param) => param,
+            None => break,
+        };
+        inferred_params.push(&param.ident);
+        inferred.extend(determined_by(&param.bounds));
+        let predicates = sig.generics.where_clause.iter().flat_map(|w| &w.predicates);
+        for predicate in predicates {
+            if let WherePredicate::Type(predicate) = predicate {
+                if mentions(predicate.bounded_ty.to_token_stream(), &param.ident) {
+                    inferred.extend(determined_by(&predicate.bounds));
+                }
+            }
+        }
+    }
+    let params = sig
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) if inferred_params.contains(&&param.ident) => {
+                Some(quote! { _ })
+            }
+            GenericParam::Type(param) => Some(param.ident.to_token_stream()),
+            GenericParam::Const(param) => Some(param.ident.to_token_stream()),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let turbofish = if params.iter().all(|param| param.to_string() == "_") {
+        None
+    } else {
+        Some(quote! { ::<#(#params),*> })
+    };
+    let body = match self_receiver {
+        Some((receiver, self_token, self_kind)) => {
+            // Replace the self token in the receiver with the token we extract above to ensure it
+            // comes from the right hygiene context.
+            let receiver = replace_self(receiver, &self_token);
+            match &call_path {
+                Some(path) => {
+                    let receiver = match self_kind {
+                        SelfKind::Ref => quote! { &#receiver },
+                        SelfKind::Mut => quote! { &mut #receiver },
+                        SelfKind::Owned => receiver,
+                    };
+                    quote! { #path #turbofish(#receiver #(, #args)*) }
+                }
+                None if has_call_as_fn => quote! { (#receiver)(#(#args),*) },
+                // Already borrowed, e.g. inside an `unsafe` block.
+                None if has_take && matches!(self_kind, SelfKind::Owned) => {
+                    quote! { ::core::mem::take(#receiver) }
+                }
+                None if has_take => quote! { ::core::mem::take(&mut #receiver) },
+                None => quote! { #receiver.#name #turbofish(#(#args),*) },
+            }
+        }
+        None if call_path.is_some() => {
+            let path = &call_path;
+            quote! { #path #turbofish(#(#args),*) }
+        }
+        None => {
+            let ty = &delegate.ty;
+            quote! { <#ty>::#name #turbofish(#(#args),*) }
+        }
+    };
+    // Await the target only if the method itself is `async`.
+    let body = if sig.asyncness.is_some() {
+        quote! { #body.await }
+    } else {
+        body
+    };
+    let body = if has_collect {
+        quote! { #body.collect() }
+    } else if has_to_owned {
+        quote! { ::std::borrow::ToOwned::to_owned(#body) }
+    } else {
+        body
+    };
+    let body = match wrap_self {
+        Some(WrapSelf::Value) => delegate.wrap(body).unwrap(),
+        Some(WrapSelf::Result) => {
+            let value = delegate.wrap(quote! { #body? }).unwrap();
+            quote! { ::std::result::Result::Ok(#value) }
+        }
+        Some(WrapSelf::Option) => {
+            let value = delegate.wrap(quote! { #body? }).unwrap();
+            quote! { ::std::option::Option::Some(#value) }
+        }
+        None => body,
+    };
+    let body = match propagate {
+        Some(wrap) => quote! { #wrap(#body) },
+        None => body,
+    };
+    // Dispatch on the variants, unless there is no `self` to match on.
+    let body = match &delegate.variants {
+        Some(variants) if sig.receiver().is_some() => {
+            let binding = &delegate.receiver;
+            quote! {
+                match #self_token {
+                    #(Self::#variants(#binding) => #body,)*
+                }
+            }
+        }
+        _ => body,
+    };
     let body = match &sig.output {
+        ReturnType::Default if has_discard => quote! { let _ = #body; },
+        ReturnType::Type(..) if returns_self_ref => quote! { #body; #self_token },
         ReturnType::Default => quote! { #body; },
+        // Bind the result first so that temporaries of the call, e.g. a guard in the receiver,
+        // are dropped before the conversion.
         ReturnType::Type(_, ty) if has_into => {
-            quote! { ::std::convert::Into::<#ty>::into(#body) }
+            let result_ty = into_ty.as_ref().map(|ty| quote! { : #ty });
+            quote! {
+                let result #result_ty = #body;
+                ::std::convert::Into::<#ty>::into(result)
+            }
         }
         _ => body,
     };
+    let allow_clippy = if delegate.allow_clippy {
+        let lints = CLIPPY_LINTS
+            .iter()
+            .map(|lint| Ident::new(lint, Span::call_site()));
+        Some(quote! { #[allow(#(clippy::#lints),*)] })
+    } else {
+        None
+    };
+    let on_call = delegate.on_call.as_ref().map(|hook| {
+        let name = LitStr::new(&sig.ident.unraw().to_string(), sig.ident.span());
+        quote! { #hook(#name); }
+    });
+    let use_ = delegate.use_.as_ref().map(|tree| quote! { use #tree; });
     quote! {
-        #(#attrs)* #inline #vis #defaultness #sig {
+        #(#attrs)* #inline #allow_clippy #vis #defaultness #sig {
+            #use_
+            #on_call
             #body
         }
     }
 }
 
+/// How `self` is borrowed in `Pin<&Self>` or `Pin<&mut Self>`.
+fn pin_kind(ty: &Type, context: &ImplContext) -> Option<SelfKind> {
+    let segment = match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+    let args = match &segment.arguments {
+        PathArguments::AngleBracketed(args) if segment.ident == "Pin" => &args.args,
+        _ => return None,
+    };
+    match args.first() {
+        Some(GenericArgument::Type(Type::Reference(ty)))
+            if args.len() == 1 && context.is_self_type(&ty.elem) =>
+        {
+            Some(self_kind(&Type::Reference(ty.clone())))
+        }
+        _ => None,
+    }
+}
+
+fn self_kind(ty: &Type) -> SelfKind {
+    match ty {
+        Type::Reference(ty) if ty.mutability.is_some() => SelfKind::Mut,
+        Type::Reference(_) => SelfKind::Ref,
+        _ => SelfKind::Owned,
+    }
+}
+
+/// Import names in the tree as `_`, which is enough for calling trait methods.
+fn anonymize(tree: UseTree) -> UseTree {
+    match tree {
+        UseTree::Path(mut path) => {
+            *path.tree = anonymize(*path.tree);
+            UseTree::Path(path)
+        }
+        UseTree::Name(name) => UseTree::Rename(UseRename {
+            ident: name.ident,
+            as_token: Default::default(),
+            rename: Ident::new("_", Span::call_site()),
+        }),
+        UseTree::Group(mut group) => {
+            group.items = group.items.into_iter().map(anonymize).collect();
+            UseTree::Group(group)
+        }
+        tree => tree,
+    }
+}
+
+/// Wrap the receiver in parentheses unless a method call can follow it directly, e.g. for
+/// `**self.0` or `&self.0`.
+fn parenthesize(expr: Expr) -> Expr {
+    match expr {
+        Expr::Array(_)
+        | Expr::Await(_)
+        | Expr::Call(_)
+        | Expr::Field(_)
+        | Expr::Index(_)
+        | Expr::Macro(_)
+        | Expr::MethodCall(_)
+        | Expr::Paren(_)
+        | Expr::Path(_)
+        | Expr::Try(_)
+        | Expr::Tuple(_) => expr,
+        expr => syn::parse_quote!((#expr)),
+    }
+}
+
+/// Find a method call returning a guard in the receiver, e.g. `lock` in
+/// `self.0.lock().unwrap().as_slice()`.
+fn guard_method(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::MethodCall(call)
+            if call.args.is_empty() && GUARDS.iter().any(|g| call.method == g) =>
+        {
+            Some(&call.method)
+        }
+        Expr::MethodCall(ExprMethodCall { receiver, .. })
+        | Expr::Field(ExprField { base: receiver, .. })
+        | Expr::Try(ExprTry { expr: receiver, .. })
+        | Expr::Paren(ExprParen { expr: receiver, .. }) => guard_method(receiver),
+        _ => None,
+    }
+}
+
+/// Check whether the type can borrow from `self`, i.e. it has an elided lifetime, `'_` or the
+/// lifetime of `self`. Lifetimes in `Fn(...)` bounds and `fn` pointers are their own.
+fn borrows_self(ty: &Type, self_lifetime: Option<&Lifetime>) -> bool {
+    let is_self = |lifetime: &Lifetime| lifetime.ident == "_" || Some(lifetime) == self_lifetime;
+    let in_path = |path: &Path| {
+        path.segments
+            .iter()
+            .any(|segment| match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| match arg {
+                    GenericArgument::Lifetime(lifetime) => is_self(lifetime),
+                    GenericArgument::Type(ty) => borrows_self(ty, self_lifetime),
+                    GenericArgument::AssocType(assoc) => borrows_self(&assoc.ty, self_lifetime),
+                    _ => false,
+                }),
+                _ => false,
+            })
+    };
+    let in_bounds = |bounds: &Punctuated<TypeParamBound, Token![+]>| {
+        bounds.iter().any(|bound| match bound {
+            TypeParamBound::Lifetime(lifetime) => is_self(lifetime),
+            TypeParamBound::Trait(bound) => in_path(&bound.path),
+            _ => false,
+        })
+    };
+    match ty {
+        Type::Reference(ty) => {
+            let borrowed = match &ty.lifetime {
+                Some(lifetime) => is_self(lifetime),
+                None => true,
+            };
+            borrowed || borrows_self(&ty.elem, self_lifetime)
+        }
+        Type::Path(ty) => {
+            let in_qself = ty.qself.as_ref();
+            in_qself.is_some_and(|qself| borrows_self(&qself.ty, self_lifetime))
+                || in_path(&ty.path)
+        }
+        Type::ImplTrait(ty) => in_bounds(&ty.bounds),
+        Type::TraitObject(ty) => in_bounds(&ty.bounds),
+        Type::Tuple(ty) => ty.elems.iter().any(|ty| borrows_self(ty, self_lifetime)),
+        Type::Array(TypeArray { elem, .. })
+        | Type::Slice(TypeSlice { elem, .. })
+        | Type::Paren(TypeParen { elem, .. })
+        | Type::Group(TypeGroup { elem, .. }) => borrows_self(elem, self_lifetime),
+        _ => false,
+    }
+}
+
+fn is_self_expr(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(ExprPath { qself: None, path, .. }) if path.is_ident("self"))
+}
+
+fn is_self_type(ty: &Type) -> bool {
+    matches!(ty, Type::Path(TypePath { qself: None, path }) if path.is_ident("Self"))
+}
+
+fn is_self_arg(arg: &FnArg) -> bool {
+    match arg {
+        FnArg::Receiver(_) => true,
+        FnArg::Typed(pat) => matches!(&*pat.pat, Pat::Ident(ident) if ident.ident == "self"),
+    }
+}
+
+fn is_delegate_attr(attr: &Attribute) -> bool {
+    let segments = &attr.path().segments;
+    segments.last().is_some_and(|s| s.ident == "delegate")
+}
+
+/// Types determined by bounds of an inferred type, i.e. associated types, e.g. `Item = T`, and
+/// outputs of `Fn(..) -> B`. Other generic arguments of the traits, e.g. `T` of `Into<T>`, are
+/// not, since the type may implement the trait for more than one of them.
+fn determined_by(bounds: &Punctuated<TypeParamBound, Token![+]>) -> Vec<TokenStream> {
+    let paths = bounds.iter().filter_map(|bound| match bound {
+        TypeParamBound::Trait(bound) => Some(&bound.path),
+        _ => None,
+    });
+    let mut types = Vec::new();
+    for segment in paths.flat_map(|path| &path.segments) {
+        match &segment.arguments {
+            PathArguments::AngleBracketed(args) => {
+                for arg in &args.args {
+                    if let GenericArgument::AssocType(assoc) = arg {
+                        types.push(assoc.ty.to_token_stream());
+                    }
+                }
+            }
+            PathArguments::Parenthesized(args) => {
+                if let ReturnType::Type(_, ty) = &args.output {
+                    types.push(ty.to_token_stream());
+                }
+            }
+            PathArguments::None => {}
+        }
+    }
+    types
+}
+
+fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token) => token == *ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
 fn replace_self(expr: TokenStream, self_token: &TokenStream) -> TokenStream {
     expr.into_iter()
         .map(|token| match token {
@@ -321,3 +2719,7 @@ fn replace_self(expr: TokenStream, self_token: &TokenStream) -> TokenStream {
         })
         .collect()
 }
+
+// Snapshots assume the default inline attribute.
+#[cfg(all(test, not(feature = "no-default-inline")))]
+mod tests;
@@ -0,0 +1,1568 @@
+//! Snapshot tests of the expanded output.
+
+use super::{delegate_all_input, delegate_input, DelegateArgs};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+macro_rules! assert_expand {
+    (#[delegate($($args:tt)*)] { $($input:tt)* } => { $($expected:tt)* }) => {
+        let delegate: DelegateArgs = syn::parse2(quote!($($args)*)).unwrap();
+        let actual: TokenStream = delegate_input(quote!($($input)*), &delegate);
+        assert_eq!(actual.to_string(), quote!($($expected)*).to_string());
+    };
+    (#[delegate_all($($args:expr),*)] { $($input:tt)* } => { $($expected:tt)* }) => {
+        let receivers = vec![$(syn::parse2(quote!($args)).unwrap()),*];
+        let actual: TokenStream = delegate_all_input(quote!($($input)*), receivers);
+        assert_eq!(actual.to_string(), quote!($($expected)*).to_string());
+    };
+}
+
+#[test]
+fn impl_block() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Foo {
+                fn len(&self) -> usize {}
+                fn into_bytes(self) -> Vec<u8> {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+                #[inline]
+                fn into_bytes(self) -> Vec<u8> {
+                    self.0.into_bytes()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn trait_impl() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl<T> Iterator for Iter<T> where T: Copy {
+                type Item = T;
+                fn next(&mut self) -> Option<T> {}
+            }
+        } => {
+            impl<T> Iterator for Iter<T> where T: Copy {
+                type Item = T;
+                #[inline]
+                fn next(&mut self) -> Option<T> {
+                    self.0.next()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn single_fn() {
+    assert_expand! {
+        #[delegate(self.inner.borrow())]
+        {
+            /// Doc.
+            pub fn len(&self) -> usize {}
+        } => {
+            /// Doc.
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.inner.borrow().len()
+            }
+        }
+    }
+}
+
+#[test]
+fn user_inline() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[inline]
+            fn len(&self) -> usize {}
+        } => {
+            #[inline]
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+    }
+}
+
+#[test]
+fn attr_order() {
+    // Added attributes come after the method's own ones.
+    assert_expand! {
+        #[delegate(self.0, allow_clippy)]
+        {
+            /// Doc.
+            #[must_use]
+            fn len(&self) -> usize {}
+        } => {
+            #[doc = r" Doc."]
+            #[must_use]
+            #[inline]
+            #[allow(
+                clippy::inline_always,
+                clippy::needless_lifetimes,
+                clippy::should_implement_trait,
+                clippy::wrong_self_convention
+            )]
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+    }
+}
+
+#[test]
+fn into() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[into]
+            fn len(&self) -> u64 {}
+        } => {
+            #[inline]
+            fn len(&self) -> u64 {
+                let result = self.0.len();
+                ::std::convert::Into::<u64>::into(result)
+            }
+        }
+    }
+}
+
+#[test]
+fn call() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[call(push)]
+            fn append(&mut self, value: u8) {}
+        } => {
+            #[inline]
+            fn append(&mut self, value: u8) {
+                self.0.push(value);
+            }
+        }
+    }
+}
+
+#[test]
+fn borrow_mut() {
+    assert_expand! {
+        #[delegate(self.0, mut)]
+        {
+            fn push(&self, value: u8) {}
+        } => {
+            #[inline]
+            fn push(&self, value: u8) {
+                self.0.borrow_mut().push(value);
+            }
+        }
+    }
+}
+
+#[test]
+fn nested_delegate() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Foo {
+                #[delegate(self.1)]
+                fn len(&self) -> usize {}
+            }
+        } => {
+            impl Foo {
+                #[delegate(self.1)]
+                fn len(&self) -> usize {}
+            }
+        }
+    }
+}
+
+#[test]
+fn accessors() {
+    assert_expand! {
+        #[delegate(self.inner, ref = borrow, mut = get_mut, owned = into_inner)]
+        {
+            impl Foo {
+                fn len(&self) -> usize {}
+                fn push(&mut self, value: u8) {}
+                fn into_vec(self) -> Vec<u8> {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn len(&self) -> usize {
+                    self.inner.borrow().len()
+                }
+                #[inline]
+                fn push(&mut self, value: u8) {
+                    self.inner.get_mut().push(value);
+                }
+                #[inline]
+                fn into_vec(self) -> Vec<u8> {
+                    self.inner.into_inner().into_vec()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn no_docs() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            /// Doc.
+            #[doc(hidden)]
+            #[doc = "More doc."]
+            #[no_docs]
+            pub fn len(&self) -> usize {}
+        } => {
+            #[doc(hidden)]
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+    }
+}
+
+#[test]
+fn construct() {
+    assert_expand! {
+        #[delegate(self.inner, ty = Inner)]
+        {
+            impl Foo {
+                #[construct]
+                fn new(value: u8) -> Self {}
+                #[construct]
+                fn try_new(value: u8) -> Result<Self, Error> {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn new(value: u8) -> Self {
+                    Self { inner: <Inner>::new(value) }
+                }
+                #[inline]
+                fn try_new(value: u8) -> Result<Self, Error> {
+                    ::std::result::Result::Ok(Self { inner: <Inner>::try_new(value)? })
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn return_self() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl<T> Foo<T> {
+                fn clone(&self) -> Self {}
+                fn split_off(&mut self, at: usize) -> Foo<T> {}
+                fn first(&self) -> Option<&T> {}
+            }
+        } => {
+            impl<T> Foo<T> {
+                #[inline]
+                fn clone(&self) -> Self {
+                    Self(self.0.clone())
+                }
+                #[inline]
+                fn split_off(&mut self, at: usize) -> Foo<T> {
+                    Self(self.0.split_off(at))
+                }
+                #[inline]
+                fn first(&self) -> Option<&T> {
+                    self.0.first()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn unsafe_receiver() {
+    assert_expand! {
+        #[delegate(self.0.field, unsafe)]
+        {
+            fn count_ones(&self) -> u32 {}
+        } => {
+            #[inline]
+            fn count_ones(&self) -> u32 {
+                (unsafe { &self.0.field }).count_ones()
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0.field, unsafe)]
+        {
+            impl Foo {
+                fn abs(self) -> f32 {}
+                fn push(&mut self, value: u8) {}
+                #[call(std::mem::take)]
+                fn take(&mut self) -> Vec<u8> {}
+                fn first(&self, index: usize) -> bool {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn abs(self) -> f32 {
+                    (unsafe { self.0.field }).abs()
+                }
+                #[inline]
+                fn push(&mut self, value: u8) {
+                    (unsafe { &mut self.0.field }).push(value);
+                }
+                #[inline]
+                fn take(&mut self) -> Vec<u8> {
+                    std::mem::take((unsafe { &mut self.0.field }))
+                }
+                #[inline]
+                fn first(&self, index: usize) -> bool {
+                    (unsafe { &self.0.field }).first(index)
+                }
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0, unsafe, mut)]
+        {
+            fn push(&self, value: u8) {}
+        } => {
+            #[inline]
+            fn push(&self, value: u8) {
+                (unsafe { self.0.borrow_mut() }).push(value);
+            }
+        }
+    }
+}
+
+#[test]
+fn on_call() {
+    assert_expand! {
+        #[delegate(self.0, on_call = trace)]
+        {
+            fn len(&self) -> usize {}
+        } => {
+            #[inline]
+            fn len(&self) -> usize {
+                trace("len");
+                self.0.len()
+            }
+        }
+    }
+}
+
+#[test]
+fn unwrap() {
+    assert_expand! {
+        #[delegate(self.0, unwrap)]
+        {
+            impl Foo {
+                fn len(&self) -> usize {}
+                #[propagate]
+                fn push(&mut self, value: u8) -> Option<()> {}
+                fn into_vec(self) -> Vec<u8> {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn len(&self) -> usize {
+                    self.0.as_ref().unwrap().len()
+                }
+                #[inline]
+                fn push(&mut self, value: u8) -> Option<()> {
+                    ::std::option::Option::Some(self.0.as_mut()?.push(value))
+                }
+                #[inline]
+                fn into_vec(self) -> Vec<u8> {
+                    self.0.unwrap().into_vec()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generic_method() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn get<'a, T, const N: usize>(&'a self) -> &'a T {}
+        } => {
+            #[inline]
+            fn get<'a, T, const N: usize>(&'a self) -> &'a T {
+                self.0.get::<T, N>()
+            }
+        }
+    }
+}
+
+#[test]
+fn closure_method() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn map<B, F: FnMut(u8) -> B>(self, f: F) -> Map<B, F> {}
+        } => {
+            #[inline]
+            fn map<B, F: FnMut(u8) -> B>(self, f: F) -> Map<B, F> {
+                self.0.map(f)
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn for_each<F: FnMut(u8)>(self, f: F) {}
+        } => {
+            #[inline]
+            fn for_each<F: FnMut(u8)>(self, f: F) {
+                self.0.for_each(f);
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn parse_with<T, F>(&self, f: F) -> T where F: Fn(&str) {}
+        } => {
+            #[inline]
+            fn parse_with<T, F>(&self, f: F) -> T where F: Fn(&str) {
+                self.0.parse_with::<T, _>(f)
+            }
+        }
+    }
+}
+
+#[test]
+fn bound_inference() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Foo {
+                fn set<T, U: Into<T> >(&self, value: U) {}
+                fn extend_from<T, I>(&mut self, iter: I) where I: IntoIterator<Item = T> {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn set<T, U: Into<T> >(&self, value: U) {
+                    self.0.set::<T, _>(value);
+                }
+                #[inline]
+                fn extend_from<T, I>(&mut self, iter: I) where I: IntoIterator<Item = T> {
+                    self.0.extend_from(iter);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn as_ref() {
+    assert_expand! {
+        #[delegate(self.0, as_ref = str, as_mut)]
+        {
+            impl Foo {
+                fn len(&self) -> usize {}
+                fn push(&mut self, value: u8) {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn len(&self) -> usize {
+                    ::std::convert::AsRef::<str>::as_ref(&self.0).len()
+                }
+                #[inline]
+                fn push(&mut self, value: u8) {
+                    self.0.as_mut().push(value);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn raw_pointer() {
+    assert_expand! {
+        #[delegate((*self.0), unsafe)]
+        {
+            fn len(&self) -> usize {}
+        } => {
+            #[inline]
+            fn len(&self) -> usize {
+                (unsafe { &(*self.0) }).len()
+            }
+        }
+    }
+}
+
+#[test]
+fn into_type() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[collect]
+            #[into(Vec<u8>)]
+            fn keys(&self) -> Rc<[u8]> {}
+        } => {
+            #[inline]
+            fn keys(&self) -> Rc<[u8]> {
+                let result: Vec<u8> = self.0.keys().collect();
+                ::std::convert::Into::<Rc<[u8]> >::into(result)
+            }
+        }
+    }
+}
+
+#[test]
+fn call_path() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Foo {
+                #[call(<Inner as Trait>::name)]
+                fn name(&self) -> &str {}
+                #[call(Inner::push)]
+                fn push(&mut self, value: u8) {}
+                #[call(crate::internal::Inner::len)]
+                fn len(&self) -> usize {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn name(&self) -> &str {
+                    <Inner as Trait>::name(&self.0)
+                }
+                #[inline]
+                fn push(&mut self, value: u8) {
+                    Inner::push(&mut self.0, value);
+                }
+                #[inline]
+                fn len(&self) -> usize {
+                    crate::internal::Inner::len(&self.0)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn self_args() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Foo {
+                fn cmp(&self, other: &Self) -> Ordering {}
+                fn append(&mut self, other: &mut Foo) {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.cmp(&other.0)
+                }
+                #[inline]
+                fn append(&mut self, other: &mut Foo) {
+                    self.0.append(&mut other.0);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn self_args_by_value() {
+    assert_expand! {
+        #[delegate(self.inner)]
+        {
+            fn merge(self, other: Self) -> Self {}
+        } => {
+            #[inline]
+            fn merge(self, other: Self) -> Self {
+                Self { inner: self.inner.merge(other.inner) }
+            }
+        }
+    }
+}
+
+#[test]
+fn defaultness() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            default impl<T> Len for Foo<T> {
+                default fn len(&self) -> usize {}
+            }
+        } => {
+            default impl<T> Len for Foo<T> {
+                #[inline]
+                default fn len(&self) -> usize {
+                    self.0.len()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn cfg_attr_inline() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[cfg_attr(feature = "fast", inline)]
+            fn len(&self) -> usize {}
+        } => {
+            #[cfg_attr(feature = "fast", inline)]
+            #[cfg_attr(not(any(feature = "fast")), inline)]
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+    }
+}
+
+#[test]
+fn allow_clippy() {
+    assert_expand! {
+        #[delegate(self.0, allow_clippy)]
+        {
+            fn next(&mut self) -> Option<u8> {}
+        } => {
+            #[inline]
+            #[allow(
+                clippy::inline_always,
+                clippy::needless_lifetimes,
+                clippy::should_implement_trait,
+                clippy::wrong_self_convention
+            )]
+            fn next(&mut self) -> Option<u8> {
+                self.0.next()
+            }
+        }
+    }
+}
+
+#[test]
+fn call_receiver() {
+    assert_expand! {
+        #[delegate(ManuallyDrop::into_inner(self.0))]
+        {
+            fn into_vec(self) -> Vec<u8> {}
+        } => {
+            #[inline]
+            fn into_vec(self) -> Vec<u8> {
+                ManuallyDrop::into_inner(self.0).into_vec()
+            }
+        }
+    }
+}
+
+#[test]
+fn to() {
+    assert_expand! {
+        #[delegate(self.0, mut)]
+        {
+            impl Foo {
+                fn len(&self) -> usize {}
+                #[to(self.1)]
+                fn push(&self, value: u8) {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn len(&self) -> usize {
+                    self.0.borrow_mut().len()
+                }
+                #[inline]
+                fn push(&self, value: u8) {
+                    self.1.borrow_mut().push(value);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn by_ref() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn f(&self, #[by_ref] a: u8, #[by_mut] mut b: u8, c: u8) {}
+        } => {
+            #[inline]
+            fn f(&self, a: u8, mut b: u8, c: u8) {
+                self.0.f(&a, &mut b, c);
+            }
+        }
+    }
+}
+
+#[test]
+fn automatically_derived() {
+    assert_expand! {
+        #[delegate(self.0, automatically_derived)]
+        {
+            #[allow(unused)]
+            impl<T> Deref for Foo<T> {
+                type Target = T;
+                fn deref(&self) -> &T {}
+            }
+        } => {
+            #[allow(unused)]
+            #[automatically_derived]
+            impl<T> Deref for Foo<T> {
+                type Target = T;
+                #[inline]
+                fn deref(&self) -> &T {
+                    self.0.deref()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn delegate_all() {
+    assert_expand! {
+        #[delegate_all(self.0, self.1)]
+        {
+            impl Pair {
+                fn push(&mut self, value: u8) {}
+            }
+        } => {
+            impl Pair {
+                #[inline]
+                fn push(&mut self, value: u8) {
+                    self.0.push(value);
+                    self.1.push(value);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn where_clause() {
+    assert_expand! {
+        #[delegate(self.0, where T: Clone)]
+        {
+            fn to_vec(&self) -> Vec<T> where T: Send {}
+        } => {
+            #[inline]
+            fn to_vec(&self) -> Vec<T> where T: Send, T: Clone {
+                self.0.to_vec()
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0, where T: Clone)]
+        {
+            impl<T> Foo<T> {
+                fn to_vec(&self) -> Vec<T> {}
+            }
+        } => {
+            impl<T> Foo<T> where T: Clone {
+                #[inline]
+                fn to_vec(&self) -> Vec<T> {
+                    self.0.to_vec()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn inline_policy() {
+    assert_expand! {
+        #[delegate(self.0, inline = never)]
+        {
+            impl Foo {
+                fn len(&self) -> usize {}
+                #[inline_always]
+                fn is_empty(&self) -> bool {}
+            }
+        } => {
+            impl Foo {
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+                #[inline(always)]
+                fn is_empty(&self) -> bool {
+                    self.0.is_empty()
+                }
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0, skip_inline)]
+        {
+            fn len(&self) -> usize {}
+        } => {
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[no_inline]
+            fn len(&self) -> usize {}
+        } => {
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0, inline = always)]
+        {
+            impl Foo {
+                #[cold]
+                fn len(&self) -> usize {}
+                #[cold]
+                #[inline_always]
+                fn is_empty(&self) -> bool {}
+            }
+        } => {
+            impl Foo {
+                #[cold]
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+                #[cold]
+                #[inline(always)]
+                fn is_empty(&self) -> bool {
+                    self.0.is_empty()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn inline_inherent() {
+    assert_expand! {
+        #[delegate(self.0, inline = inherent)]
+        {
+            impl Foo {
+                fn len(&self) -> usize {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0, inline = inherent)]
+        {
+            impl Iterator for Foo {
+                type Item = u8;
+                fn next(&mut self) -> Option<u8> {}
+                #[inline_always]
+                fn count(self) -> usize {}
+            }
+        } => {
+            impl Iterator for Foo {
+                type Item = u8;
+                fn next(&mut self) -> Option<u8> {
+                    self.0.next()
+                }
+                #[inline(always)]
+                fn count(self) -> usize {
+                    self.0.count()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn construct_implicit() {
+    assert_expand! {
+        #[delegate(self.0, ty = Vec<T>)]
+        {
+            impl<T> Foo<T> {
+                fn with_capacity(capacity: usize) -> Self {}
+            }
+        } => {
+            impl<T> Foo<T> {
+                #[inline]
+                fn with_capacity(capacity: usize) -> Self {
+                    Self(<Vec<T> >::with_capacity(capacity))
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn assoc_const() {
+    assert_expand! {
+        #[delegate(self.0, ty = u8)]
+        {
+            impl Foo {
+                const MAX: u8;
+                const ZERO: u8 = 0;
+                fn count_ones(&self) -> u32 {}
+            }
+        } => {
+            impl Foo {
+                const MAX: u8 = <u8>::MAX;
+                const ZERO: u8 = 0;
+                #[inline]
+                fn count_ones(&self) -> u32 {
+                    self.0.count_ones()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn raw_ident() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Foo {
+                fn r#type(&self) -> u8 {}
+                #[call(r#match)]
+                fn r#loop<T>(&self) -> T {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn r#type(&self) -> u8 {
+                    self.0.r#type()
+                }
+                #[inline]
+                fn r#loop<T>(&self) -> T {
+                    self.0.r#match::<T>()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn bind() {
+    assert_expand! {
+        #[delegate(self.renderer)]
+        {
+            #[bind(theme = &self.theme, width = 80)]
+            fn render(&self, text: &str) -> String {}
+        } => {
+            #[inline]
+            fn render(&self, text: &str) -> String {
+                self.renderer.render(&self.theme, 80, text)
+            }
+        }
+    }
+}
+
+#[test]
+fn async_method() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Foo {
+                async fn get(&self, index: usize) -> Option<u8> {}
+                #[call(get)]
+                fn get_future(&self, index: usize) -> impl Future<Output = Option<u8> > + '_ {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                async fn get(&self, index: usize) -> Option<u8> {
+                    self.0.get(index).await
+                }
+                #[inline]
+                fn get_future(&self, index: usize) -> impl Future<Output = Option<u8> > + '_ {
+                    self.0.get(index)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn enum_variants() {
+    assert_expand! {
+        #[delegate(enum(A, B))]
+        {
+            fn len(&self) -> usize {}
+        } => {
+            #[inline]
+            fn len(&self) -> usize {
+                match self {
+                    Self::A(target) => target.len(),
+                    Self::B(target) => target.len(),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn discard() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[discard]
+            fn insert(&mut self, value: u8) {}
+        } => {
+            #[inline]
+            fn insert(&mut self, value: u8) {
+                let _ = self.0.insert(value);
+            }
+        }
+    }
+}
+
+#[test]
+fn pin() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Foo {
+                fn get(self: Pin<&Self>) -> u32 {}
+                fn bump(self: Pin<&mut Self>) {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn get(self: Pin<&Self>) -> u32 {
+                    ::std::pin::Pin::new(&::std::pin::Pin::get_ref(self).0).get()
+                }
+                #[inline]
+                fn bump(self: Pin<&mut Self>) {
+                    ::std::pin::Pin::new(&mut ::std::pin::Pin::get_mut(self).0).bump();
+                }
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0, unsafe_pin)]
+        {
+            fn bump(self: Pin<&mut Self>) {}
+        } => {
+            #[inline]
+            fn bump(self: Pin<&mut Self>) {
+                unsafe { ::std::pin::Pin::map_unchecked_mut(self, |this| &mut this.0) }.bump();
+            }
+        }
+    }
+}
+
+#[test]
+fn vis() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[vis(pub(in crate::foo))]
+            impl Foo {
+                fn len(&self) -> usize {}
+                pub(crate) fn is_empty(&self) -> bool {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                pub(in crate::foo) fn len(&self) -> usize {
+                    self.0.len()
+                }
+                #[inline]
+                pub(crate) fn is_empty(&self) -> bool {
+                    self.0.is_empty()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn into_args() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[into_args]
+            fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {}
+        } => {
+            #[inline]
+            fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+                self.0.insert(::std::convert::Into::into(key), ::std::convert::Into::into(value));
+            }
+        }
+    }
+}
+
+#[test]
+fn deref_receiver() {
+    assert_expand! {
+        #[delegate(**self.0)]
+        {
+            fn len(&self) -> usize {}
+        } => {
+            #[inline]
+            fn len(&self) -> usize {
+                (**self.0).len()
+            }
+        }
+    }
+}
+
+#[test]
+fn assoc_const_self() {
+    assert_expand! {
+        #[delegate(self.0, ty = u8)]
+        {
+            const MAX: Self;
+        } => {
+            const MAX: Self = Self(<u8>::MAX);
+        }
+    }
+}
+
+#[test]
+fn to_owned() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[to_owned]
+            fn name(&self) -> String {}
+        } => {
+            #[inline]
+            fn name(&self) -> String {
+                ::std::borrow::ToOwned::to_owned(self.0.name())
+            }
+        }
+    }
+}
+
+#[test]
+fn use_trait() {
+    assert_expand! {
+        #[delegate(self.0, use = ext::{Double, Halve})]
+        {
+            fn double(&self) -> u32 {}
+        } => {
+            #[inline]
+            fn double(&self) -> u32 {
+                use ext::{Double as _, Halve as _};
+                self.0.double()
+            }
+        }
+    }
+}
+
+#[test]
+fn self_projection() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Add for Foo {
+                type Output = Self;
+                fn add(self, rhs: Self) -> Self::Output {}
+            }
+        } => {
+            impl Add for Foo {
+                type Output = Self;
+                #[inline]
+                fn add(self, rhs: Self) -> Self::Output {
+                    self.0.add(rhs.0)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn expect() {
+    assert_expand! {
+        #[delegate(self.0, expect = "empty")]
+        {
+            fn len(&self) -> usize {}
+        } => {
+            #[inline]
+            fn len(&self) -> usize {
+                self.0.as_ref().expect("empty").len()
+            }
+        }
+    }
+}
+
+#[test]
+fn generic_builder() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn with<T>(self, name: T) -> Self where T: Into<String> {}
+        } => {
+            #[inline]
+            fn with<T>(self, name: T) -> Self where T: Into<String> {
+                Self(self.0.with(name))
+            }
+        }
+    }
+}
+
+#[test]
+fn cast_receiver() {
+    assert_expand! {
+        #[delegate(self.0 as i64)]
+        {
+            fn pow(&self, exp: u32) -> i64 {}
+        } => {
+            #[inline]
+            fn pow(&self, exp: u32) -> i64 {
+                (self.0 as i64).pow(exp)
+            }
+        }
+    }
+}
+
+#[test]
+fn skip_arg() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[to(self.0.prepare(sql))]
+            fn query<T>(&self, #[skip] sql: T, limit: usize) -> Rows {}
+        } => {
+            #[inline]
+            fn query<T>(&self, sql: T, limit: usize) -> Rows {
+                self.0.prepare(sql).query(limit)
+            }
+        }
+    }
+}
+
+#[test]
+fn return_self_ref() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn set(&mut self, value: u8) -> &mut Self {}
+        } => {
+            #[inline]
+            fn set(&mut self, value: u8) -> &mut Self {
+                self.0.set(value);
+                self
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn max<'a>(&'a self, other: &'a Self) -> &'a Self {}
+        } => {
+            #[inline]
+            fn max<'a>(&'a self, other: &'a Self) -> &'a Self {
+                self.0.max(&other.0)
+            }
+        }
+    }
+}
+
+#[test]
+fn generic_where_clause() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn get<Q>(&self, key: &Q) -> Option<&V> where K: Borrow<Q>, Q: Hash + Eq + ?Sized {}
+        } => {
+            #[inline]
+            fn get<Q>(&self, key: &Q) -> Option<&V> where K: Borrow<Q>, Q: Hash + Eq + ?Sized {
+                self.0.get(key)
+            }
+        }
+    }
+}
+
+#[test]
+fn unit_shapes() {
+    assert_expand! {
+        #[delegate(self.0, unsafe)]
+        {
+            impl Foo {
+                fn clear(&mut self) {}
+                #[discard]
+                fn push(&mut self, value: u8) {}
+            }
+        } => {
+            impl Foo {
+                #[inline]
+                fn clear(&mut self) {
+                    (unsafe { &mut self.0 }).clear();
+                }
+                #[inline]
+                fn push(&mut self, value: u8) {
+                    let _ = (unsafe { &mut self.0 }).push(value);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn via() {
+    assert_expand! {
+        #[delegate(self.0, via = as_slice)]
+        {
+            fn first(&self) -> Option<&u8> {}
+        } => {
+            #[inline]
+            fn first(&self) -> Option<&u8> {
+                self.0.as_slice().first()
+            }
+        }
+    }
+}
+
+#[test]
+fn call_as_fn() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[call_as_fn]
+            fn call(&self, x: u32) -> u32 {}
+        } => {
+            #[inline]
+            fn call(&self, x: u32) -> u32 {
+                (self.0)(x)
+            }
+        }
+    }
+}
+
+#[test]
+fn must_use_all() {
+    assert_expand! {
+        #[delegate(self.0, must_use_all)]
+        {
+            impl Foo {
+                fn len(&self) -> usize {}
+                #[must_use = "reason"]
+                fn is_empty(&self) -> bool {}
+                fn clear(&mut self) {}
+            }
+        } => {
+            impl Foo {
+                #[must_use]
+                #[inline]
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+                #[must_use = "reason"]
+                #[inline]
+                fn is_empty(&self) -> bool {
+                    self.0.is_empty()
+                }
+                #[inline]
+                fn clear(&mut self) {
+                    self.0.clear();
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn unsafe_block_receiver() {
+    assert_expand! {
+        #[delegate(unsafe { &*self.0.get() })]
+        {
+            impl Slab {
+                fn len(&self) -> usize {}
+                #[to(unsafe { &mut *self.0.get() })]
+                fn push(&mut self, value: u8) {}
+            }
+        } => {
+            impl Slab {
+                #[inline]
+                fn len(&self) -> usize {
+                    (unsafe { &*self.0.get() }).len()
+                }
+                #[inline]
+                fn push(&mut self, value: u8) {
+                    (unsafe { &mut *self.0.get() }).push(value);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn call_args() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            impl Client {
+                #[call_args(host, 443, self.timeout)]
+                fn connect(&self, host: &str) -> String {}
+            }
+        } => {
+            impl Client {
+                #[inline]
+                fn connect(&self, host: &str) -> String {
+                    self.0.connect(host, 443, self.timeout)
+                }
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[call_args(crate::DEFAULT_HOST, self::config::PORT, Self::TIMEOUT, self.retries)]
+            fn connect(&self) -> String {}
+        } => {
+            #[inline]
+            fn connect(&self) -> String {
+                self.0.connect(crate::DEFAULT_HOST, self::config::PORT, Self::TIMEOUT, self.retries)
+            }
+        }
+    }
+}
+
+#[test]
+fn take() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            #[take]
+            fn take_buffer(&mut self) -> Vec<u8> {}
+        } => {
+            #[inline]
+            fn take_buffer(&mut self) -> Vec<u8> {
+                ::core::mem::take(&mut self.0)
+            }
+        }
+    }
+}
+
+#[test]
+fn self_sized() {
+    assert_expand! {
+        #[delegate(self.0)]
+        {
+            fn collect<B: FromIterator<u8>>(self) -> B
+            where
+                Self: Sized,
+            {
+            }
+        } => {
+            #[inline]
+            fn collect<B: FromIterator<u8> >(self) -> B
+            where
+                Self: Sized,
+            {
+                self.0.collect::<B>()
+            }
+        }
+    }
+}
+
+#[test]
+fn debug() {
+    assert_expand! {
+        #[delegate(self.0, debug)]
+        {
+            fn len(&self) -> usize {}
+        } => {
+            #[inline]
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+    }
+}
+
+#[test]
+fn index_receiver() {
+    assert_expand! {
+        #[delegate(self.0[KEY])]
+        {
+            fn len(&self) -> usize {}
+        } => {
+            #[inline]
+            fn len(&self) -> usize {
+                self.0[KEY].len()
+            }
+        }
+    }
+}
+
+#[test]
+fn assoc_type() {
+    assert_expand! {
+        #[delegate(self.inner, ty = F, unsafe_pin)]
+        {
+            impl<F: Future> Future for Wrapper<F> {
+                type Output;
+                fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {}
+            }
+        } => {
+            impl<F: Future> Future for Wrapper<F> {
+                type Output = <F as Future>::Output;
+                #[inline]
+                fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                    unsafe { ::std::pin::Pin::map_unchecked_mut(self, |this| &mut this.inner) }
+                        .poll(cx)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn self_args_converted() {
+    assert_expand! {
+        #[delegate(self.0, unwrap)]
+        {
+            fn cmp(&self, other: &Self) -> Ordering {}
+        } => {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.as_ref().unwrap().cmp(other.0.as_ref().unwrap())
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0, as_ref)]
+        {
+            fn cmp(&self, other: &Self) -> Ordering {}
+        } => {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.as_ref().cmp(other.0.as_ref())
+            }
+        }
+    }
+    assert_expand! {
+        #[delegate(self.0.field, unsafe)]
+        {
+            fn total_cmp(&self, other: &Self) -> Ordering {}
+        } => {
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                (unsafe { &self.0.field }).total_cmp((unsafe { &other.0.field }))
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+
+struct Foo(RefCell<Vec<u8>>);
+
+#[delegate(self.0, mut, ref = borrow)]
+impl Foo {
+    fn len(&self) -> usize {}
+}
+
+fn main() {}
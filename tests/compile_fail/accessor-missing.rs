@@ -0,0 +1,12 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+
+struct Foo(RefCell<Vec<u8>>);
+
+#[delegate(self.0, ref = borrow)]
+impl Foo {
+    fn len(&self) -> usize {}
+    fn into_boxed_slice(self) -> Box<[u8]> {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[allow_missing_self]
+    fn checksum(bytes: &[u8]) -> u8 {}
+}
+
+fn main() {}
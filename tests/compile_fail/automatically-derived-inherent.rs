@@ -0,0 +1,10 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0, automatically_derived)]
+impl Foo {
+    fn len(&self) -> usize {}
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>, u8);
+
+#[delegate(self.0)]
+impl Foo {
+    #[bind(value = self.1)]
+    fn push(&mut self, value: u8) {}
+
+    #[bind(index = 0, index = 1)]
+    fn remove(&mut self) -> u8 {}
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    fn contains(&self, #[by_ref] #[by_mut] x: u8) -> bool {}
+    fn ends_with(&self, #[by_ref] #[by_ref] x: [u8; 1]) -> bool {}
+    fn starts_with(&self, #[by_ref(x)] x: [u8; 1]) -> bool {}
+}
+
+fn main() {}
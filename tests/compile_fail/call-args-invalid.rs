@@ -0,0 +1,19 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>, u8);
+
+#[delegate(self.0)]
+impl Foo {
+    #[call_args(self.1)]
+    #[call_args(self.1)]
+    fn push(&mut self) {}
+
+    #[bind(value = self.1)]
+    #[call_args(self.1)]
+    fn insert(&mut self, index: usize) {}
+
+    #[call_args(0 0)]
+    fn remove(&mut self) -> u8 {}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use delegate_attr::delegate;
+
+struct Callback(fn(u32) -> u32);
+
+#[delegate(self.0, ty = u32)]
+impl Callback {
+    #[call_as_fn]
+    #[call(call)]
+    fn call(&self, x: u32) -> u32 {}
+
+    #[call_as_fn]
+    #[allow_missing_self]
+    fn zero() -> u32 {}
+}
+
+fn main() {}
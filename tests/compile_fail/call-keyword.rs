@@ -0,0 +1,22 @@
+use delegate_attr::delegate;
+
+struct Inner;
+
+impl Inner {
+    fn r#type(&self) -> u8 {
+        0
+    }
+}
+
+struct Foo(Inner);
+
+#[delegate(self.0)]
+impl Foo {
+    #[call(type)]
+    fn kind(&self) -> u8 {}
+
+    #[call(self)]
+    fn me(&self) -> u8 {}
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[collect]
+    fn iter(&self) {}
+
+    #[collect]
+    fn drain(&mut self) -> impl Iterator<Item = u8> {}
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+use delegate_attr::delegate;
+
+struct Wrapper(Vec<u8>);
+
+#[delegate(self.0, ty = Vec<u8>)]
+impl Wrapper {
+    #[construct]
+    #[collect]
+    fn new() -> Self {}
+
+    #[construct]
+    #[allow_missing_self]
+    fn with_capacity(capacity: usize) -> Self {}
+
+    #[construct]
+    #[to(self.0)]
+    fn default() -> Self {}
+
+    #[construct]
+    #[call_as_fn]
+    fn from_fn() -> Self {}
+
+    #[construct]
+    #[take]
+    fn take() -> Self {}
+}
+
+struct Lazy(Option<Vec<u8>>);
+
+#[delegate(self.0, ty = Vec<u8>, unwrap)]
+impl Lazy {
+    #[construct]
+    #[propagate]
+    fn new() -> Option<Self> {}
+}
+
+fn main() {}
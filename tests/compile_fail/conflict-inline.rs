@@ -0,0 +1,30 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[inline]
+    #[no_inline]
+    fn len(&self) -> usize {}
+
+    #[inline_always]
+    #[no_inline]
+    fn is_empty(&self) -> bool {}
+
+    #[inline]
+    #[inline_always]
+    fn first(&self) -> Option<&u8> {}
+}
+
+#[delegate(self.0, inline = sometimes)]
+impl Foo {
+    fn capacity(&self) -> usize {}
+}
+
+impl Foo {
+    #[delegate(self.0, inline = inherent)]
+    fn is_ascii(&self) -> bool {}
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use delegate_attr::delegate;
+
+struct Wrapper(u8);
+
+#[delegate(self.0, ty = u8)]
+impl From<u8> for Wrapper {
+    #[construct]
+    #[into]
+    fn from(value: u8) -> Self {}
+}
+
+fn main() {}
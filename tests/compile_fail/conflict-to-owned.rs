@@ -0,0 +1,18 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<String>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[to_owned]
+    #[collect]
+    #[call(iter)]
+    fn names(&self) -> Vec<String> {}
+
+    #[to_owned]
+    #[discard]
+    #[call(first)]
+    fn touch(&self) {}
+}
+
+fn main() {}
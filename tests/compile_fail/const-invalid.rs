@@ -0,0 +1,15 @@
+use delegate_attr::delegate;
+
+struct Wrapper(u8);
+
+#[delegate(self.0.clone(), ty = u8)]
+impl Wrapper {
+    const MAX: Self;
+}
+
+impl Wrapper {
+    #[delegate(self.0, ty = u8)]
+    const MIN: u8 = 0;
+}
+
+fn main() {}
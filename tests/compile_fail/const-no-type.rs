@@ -0,0 +1,10 @@
+use delegate_attr::delegate;
+
+struct Wrapper(u8);
+
+#[delegate(self.0)]
+impl Wrapper {
+    const MAX: u8;
+}
+
+fn main() {}
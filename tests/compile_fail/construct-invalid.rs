@@ -0,0 +1,23 @@
+use delegate_attr::delegate;
+
+struct Wrapper(Vec<u8>);
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[construct]
+    fn new() -> Self {}
+}
+
+#[delegate(self.0, ty = Vec<u8>)]
+impl Wrapper {
+    #[construct]
+    fn with_capacity(&self, capacity: usize) -> Vec<u8> {}
+}
+
+#[delegate(self.0.clone(), ty = Vec<u8>)]
+impl Wrapper {
+    #[construct]
+    fn default() -> Self {}
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use delegate_attr::delegate_all;
+
+struct Pair(Vec<u8>, Vec<u8>);
+
+#[delegate_all(self.0, self.1)]
+impl Pair {
+    fn len(&self) -> usize {}
+}
+
+#[delegate_all()]
+impl Pair {
+    fn clear(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+
+struct Foo(RefCell<Vec<u8>>);
+
+#[delegate(self.0, mut, mut)]
+impl Foo {
+    fn push(&self, value: u8) {}
+}
+
+fn main() {}
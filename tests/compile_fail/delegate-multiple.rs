@@ -0,0 +1,15 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>, Vec<u8>);
+
+#[delegate(self.0, self.1)]
+impl Foo {
+    fn len(&self) -> usize {}
+}
+
+#[delegate()]
+impl Foo {
+    fn is_empty(&self) -> bool {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[discard]
+    fn pop(&mut self) -> Option<u8> {}
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[no_docs]
+    #[no_docs]
+    fn len(&self) -> usize {}
+}
+
+fn main() {}
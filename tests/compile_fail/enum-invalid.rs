@@ -0,0 +1,18 @@
+use delegate_attr::delegate;
+
+enum Either {
+    Left(Vec<u8>),
+    Right(Vec<u8>),
+}
+
+#[delegate(enum())]
+impl Either {
+    fn len(&self) -> usize {}
+}
+
+#[delegate(enum(Left, Right))]
+impl Either {
+    fn append(&mut self, other: &mut Self) {}
+}
+
+fn main() {}
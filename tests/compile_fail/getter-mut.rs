@@ -0,0 +1,18 @@
+use delegate_attr::delegate;
+
+struct Foo {
+    inner: Vec<u8>,
+}
+
+impl Foo {
+    fn inner(&self) -> &Vec<u8> {
+        &self.inner
+    }
+}
+
+#[delegate(self.inner())]
+impl Foo {
+    fn push(&mut self, value: u8) {}
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    fn first(&self) -> Option<&u8> {
+        self.0.first()
+    }
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn name(&self) -> &'static str {
+        "inner"
+    }
+}
+
+struct Foo(RefCell<Inner>);
+
+#[delegate(self.0.borrow())]
+impl Foo {
+    fn first(&self) -> Option<&u8> {}
+    // Values not borrowing from the guard are fine.
+    fn len(&self) -> usize {}
+    fn name(&self) -> &'static str {}
+}
+
+struct Bar(Mutex<Vec<u8>>);
+
+#[delegate(self.0.lock().unwrap())]
+impl Bar {
+    fn iter(&self) -> std::slice::Iter<'_, u8> {}
+}
+
+fn main() {}
@@ -12,7 +12,7 @@ struct Wrapper(Inner);
 
 #[delegate(self.0)]
 impl Wrapper {
-    #[into(a)]
+    #[into(1)]
     fn answer(&self) -> u64 {}
 }
 
@@ -3,7 +3,7 @@ use delegate_attr::delegate;
 struct Foo(Vec<u8>);
 
 #[delegate(x)]
-#[cfg(not(fake))]
+#[cfg(all())]
 impl Foo {
     x
 }
@@ -0,0 +1,18 @@
+#![deny(unused_must_use)]
+
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0, must_use_all)]
+impl Foo {
+    fn len(&self) -> usize {}
+    #[must_use = "check whether it's empty"]
+    fn is_empty(&self) -> bool {}
+}
+
+fn main() {
+    let foo = Foo(vec![]);
+    foo.len();
+    foo.is_empty();
+}
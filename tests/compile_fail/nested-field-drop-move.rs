@@ -0,0 +1,16 @@
+use delegate_attr::delegate;
+
+struct Outer(Middle);
+struct Middle(Vec<u8>);
+
+impl Drop for Middle {
+    fn drop(&mut self) {}
+}
+
+#[delegate(self.0.0)]
+impl Outer {
+    fn len(&self) -> usize {}
+    fn into_boxed_slice(self) -> Box<[u8]> {}
+}
+
+fn main() {}
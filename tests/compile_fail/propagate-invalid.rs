@@ -0,0 +1,17 @@
+use delegate_attr::delegate;
+
+struct Foo(Option<Vec<u8>>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[propagate]
+    fn len(&self) -> Option<usize> {}
+}
+
+#[delegate(self.0, unwrap)]
+impl Foo {
+    #[propagate]
+    fn is_empty(&self) -> bool {}
+}
+
+fn main() {}
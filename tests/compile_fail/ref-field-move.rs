@@ -0,0 +1,19 @@
+use delegate_attr::delegate;
+use std::fs::File;
+
+trait Close {
+    fn close(self);
+}
+
+impl Close for File {
+    fn close(self) {}
+}
+
+struct View<'a>(&'a mut File);
+
+#[delegate(self.0)]
+impl<'a> View<'a> {
+    fn close(self) {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+#[rename(len = size, len = length)]
+impl Foo {
+    fn size(&self) -> usize {}
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+#[rename(len = size, capacity = cap)]
+impl Foo {
+    fn size(&self) -> usize {}
+    fn capacity(&self) -> usize {}
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use delegate_attr::delegate;
+
+struct Name(String);
+
+impl Name {
+    fn longest<'a>(&'a self, other: &'a Name) -> &'a Name {
+        if other.0.len() > self.0.len() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+struct Wrapper(Name);
+
+// The result may be `other`, so it's not replaced with `self`.
+#[delegate(self.0)]
+impl Wrapper {
+    fn longest<'a>(&'a self, other: &'a Self) -> &'a Self {}
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0, inline = always, skip_inline)]
+impl Foo {
+    fn len(&self) -> usize {}
+}
+
+#[delegate(self.0, skip_inline, inline = never)]
+impl Foo {
+    fn is_empty(&self) -> bool {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    fn push(&mut self, #[skip] #[skip] value: u8) {}
+    fn contains(&self, #[skip] #[by_ref] value: u8) -> bool {}
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[take]
+    fn take(&self) -> Vec<u8> {}
+
+    #[take]
+    fn take_from(&mut self, other: Vec<u8>) -> Vec<u8> {}
+
+    #[take]
+    #[call(clone)]
+    fn take_clone(&mut self) -> Vec<u8> {}
+
+    #[take]
+    #[call_as_fn]
+    fn take_call(&mut self) -> Vec<u8> {}
+}
+
+fn main() {}
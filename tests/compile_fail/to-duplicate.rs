@@ -0,0 +1,15 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>, Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[to(self.1)]
+    #[to(self.1)]
+    fn len(&self) -> usize {}
+
+    #[to(self.1,)]
+    fn is_empty(&self) -> bool {}
+}
+
+fn main() {}
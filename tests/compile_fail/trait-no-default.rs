@@ -0,0 +1,10 @@
+use delegate_attr::delegate;
+
+trait HasInner {
+    fn inner(&self) -> &Vec<u8>;
+
+    #[delegate(self.inner())]
+    fn len(&self) -> usize;
+}
+
+fn main() {}
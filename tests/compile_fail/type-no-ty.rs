@@ -0,0 +1,14 @@
+use delegate_attr::delegate;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+struct Wrap(future::Ready<u8>);
+
+#[delegate(self.0)]
+impl Future for Wrap {
+    type Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {}
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use delegate_attr::delegate;
+
+struct Inner(u8);
+
+impl Inner {
+    unsafe fn danger(&self) -> u8 {
+        self.0
+    }
+}
+
+struct Foo {
+    inner: Inner,
+}
+
+// Only the access to the target is inside the `unsafe` block, not the call.
+#[delegate(self.inner, unsafe)]
+impl Foo {
+    fn danger(&self) -> u8 {}
+}
+
+fn main() {}
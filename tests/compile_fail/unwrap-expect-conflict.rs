@@ -0,0 +1,15 @@
+use delegate_attr::delegate;
+
+struct Foo(Option<Vec<u8>>);
+
+#[delegate(self.0, unwrap, expect = "no vec")]
+impl Foo {
+    fn len(&self) -> usize {}
+}
+
+#[delegate(self.0, expect = "no vec", unwrap)]
+impl Foo {
+    fn is_empty(&self) -> bool {}
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+#[vis(pub)]
+impl AsRef<[u8]> for Foo {
+    fn as_ref(&self) -> &[u8] {}
+}
+
+#[delegate(self.0)]
+#[vis(pub)]
+#[vis(pub(crate))]
+impl Foo {
+    fn len(&self) -> usize {}
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use delegate_attr::delegate;
+
+struct Wrapper<T>(Vec<T>);
+
+impl<T> Wrapper<T> {
+    #[delegate(self.0, where T: Clone, inline = never)]
+    fn to_vec(&self) -> Vec<T> {}
+}
+
+struct Maybe<T>(Option<Vec<T>>);
+
+impl<T> Maybe<T> {
+    #[delegate(self.0, where T: Clone, unwrap)]
+    fn to_vec(&self) -> Vec<T> {}
+}
+
+fn main() {}
@@ -2,5 +2,9 @@
 fn compile_test() {
     let t = trybuild::TestCases::new();
     t.pass("tests/pass/*.rs");
+    // Spans of errors differ on nightly compilers.
+    #[cfg(not(feature = "nightly-tests"))]
     t.compile_fail("tests/compile_fail/*.rs");
+    #[cfg(feature = "nightly-tests")]
+    t.pass("tests/nightly/*.rs");
 }
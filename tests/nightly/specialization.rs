@@ -0,0 +1,38 @@
+#![feature(specialization)]
+#![allow(incomplete_features)]
+
+use delegate_attr::delegate;
+
+trait Len {
+    fn len(&self) -> usize;
+}
+
+struct Wrapper<T>(Vec<T>);
+
+#[delegate(self.0)]
+impl<T> Len for Wrapper<T> {
+    default fn len(&self) -> usize {}
+}
+
+impl Len for Wrapper<u8> {
+    fn len(&self) -> usize {
+        0
+    }
+}
+
+trait Capacity {
+    fn capacity(&self) -> usize;
+}
+
+#[delegate(self.0)]
+default impl<T> Capacity for Wrapper<T> {
+    fn capacity(&self) -> usize {}
+}
+
+impl Capacity for Wrapper<u8> {}
+
+fn main() {
+    assert_eq!(Wrapper(vec!['a']).len(), 1);
+    assert_eq!(Wrapper(vec![1u8]).len(), 0);
+    assert!(Wrapper(Vec::<u8>::with_capacity(4)).capacity() >= 4);
+}
@@ -0,0 +1,44 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+impl Foo {
+    /// Doc before.
+    #[cfg(all())]
+    #[delegate(self.0)]
+    fn len(&self) -> usize {}
+
+    #[delegate(self.0)]
+    /// Doc after.
+    #[cfg(all())]
+    fn is_empty(&self) -> bool {}
+
+    #[cfg(all())]
+    #[delegate(self.0)]
+    #[allow(dead_code)]
+    /// Doc at the end.
+    fn capacity(&self) -> usize {}
+
+    #[delegate(self.0)]
+    #[cfg(any())]
+    fn missing(&self) -> usize {}
+
+    #[cfg(any())]
+    #[delegate(self.0)]
+    fn missing(&self) -> usize {}
+}
+
+// Would conflict with the methods above if they were not configured out.
+impl Foo {
+    fn missing(&self) -> usize {
+        0
+    }
+}
+
+fn main() {
+    let foo = Foo(vec![1]);
+    assert_eq!(foo.len(), 1);
+    assert!(!foo.is_empty());
+    assert!(foo.capacity() >= 1);
+    assert_eq!(foo.missing(), 0);
+}
@@ -0,0 +1,26 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+
+struct Foo(RefCell<Vec<u8>>);
+
+#[delegate(self.0.borrow())]
+impl Foo {
+    fn len(&self) -> usize {}
+
+    #[delegate(self.0, mut)]
+    fn push(&self, value: u8) {}
+}
+
+impl Foo {
+    #[delegate(self.0, mut)]
+    fn pop(&self) -> Option<u8> {}
+}
+
+fn main() {
+    let foo = Foo(RefCell::new(vec![1]));
+    assert_eq!(foo.len(), 1);
+    foo.push(2);
+    assert_eq!(foo.len(), 2);
+    assert_eq!(foo.pop(), Some(2));
+    assert_eq!(foo.len(), 1);
+}
@@ -0,0 +1,22 @@
+#![deny(unused_attributes)]
+
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    #[cfg_attr(all(), inline)]
+    fn len(&self) -> usize {}
+    #[cfg_attr(any(), inline(never))]
+    fn is_empty(&self) -> bool {}
+    #[cfg_attr(all(), doc = "Capacity.")]
+    fn capacity(&self) -> usize {}
+}
+
+fn main() {
+    let foo = Foo(vec![1]);
+    assert_eq!(foo.len(), 1);
+    assert!(!foo.is_empty());
+    assert!(foo.capacity() >= 1);
+}
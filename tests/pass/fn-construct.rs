@@ -0,0 +1,78 @@
+use delegate_attr::delegate;
+use std::convert::TryFrom;
+
+#[derive(Debug, PartialEq)]
+struct Even(u32);
+
+#[derive(Debug, PartialEq)]
+struct OddError;
+
+impl Even {
+    fn new(value: u32) -> Self {
+        Even(value * 2)
+    }
+    fn try_new(value: u64) -> Result<Self, OddError> {
+        if value % 2 == 0 {
+            Ok(Even(value as u32))
+        } else {
+            Err(OddError)
+        }
+    }
+    fn checked(value: u32) -> Option<Self> {
+        Self::try_new(value.into()).ok()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Wrapper(Even);
+
+#[delegate(self.0, ty = Even)]
+impl From<u32> for Wrapper {
+    #[construct]
+    #[call(new)]
+    fn from(value: u32) -> Self {}
+}
+
+#[delegate(self.0, ty = Even)]
+impl TryFrom<u64> for Wrapper {
+    type Error = OddError;
+
+    #[construct]
+    #[call(try_new)]
+    fn try_from(value: u64) -> Result<Self, OddError> {}
+}
+
+#[derive(Debug, PartialEq)]
+struct Named {
+    inner: Even,
+}
+
+#[delegate(self.inner, ty = Even)]
+impl Named {
+    #[construct]
+    fn checked(value: u32) -> Option<Self> {}
+}
+
+struct Custom(Even, u8);
+
+impl Custom {
+    fn from_even(even: Even) -> Self {
+        Custom(even, 0)
+    }
+}
+
+#[delegate(self.0, ty = Even, wrap = Custom::from_even)]
+impl Custom {
+    #[construct]
+    fn new(value: u32) -> Self {}
+}
+
+fn main() {
+    assert_eq!(Wrapper::from(2), Wrapper(Even(4)));
+    assert_eq!(Wrapper::try_from(4u64), Ok(Wrapper(Even(4))));
+    assert_eq!(Wrapper::try_from(3u64), Err(OddError));
+    assert_eq!(Named::checked(2), Some(Named { inner: Even(2) }));
+    assert_eq!(Named::checked(1), None);
+    let custom = Custom::new(1);
+    assert_eq!((custom.0, custom.1), (Even(2), 0));
+}
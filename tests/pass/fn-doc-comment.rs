@@ -0,0 +1,30 @@
+#![deny(missing_docs)]
+//! Doc comments around `#[delegate]` are kept.
+
+use delegate_attr::delegate;
+
+/// A wrapper.
+pub struct Foo(Vec<u8>);
+
+impl Foo {
+    /// Doc comment before the attribute.
+    #[delegate(self.0)]
+    pub fn len(&self) -> usize {}
+
+    #[delegate(self.0)]
+    /// Doc comment after the attribute.
+    #[doc = "More docs."]
+    pub fn is_empty(&self) -> bool {}
+
+    #[delegate(self.0)]
+    /// Doc comment before another attribute.
+    #[call(capacity)]
+    pub fn cap(&self) -> usize {}
+}
+
+fn main() {
+    let foo = Foo(vec![1]);
+    assert_eq!(foo.len(), 1);
+    assert!(!foo.is_empty());
+    assert!(foo.cap() >= 1);
+}
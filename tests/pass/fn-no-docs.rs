@@ -0,0 +1,21 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+impl Foo {
+    /// Returns the length.
+    #[no_docs]
+    fn len(&self) -> usize {}
+
+    /// Returns whether it's empty.
+    #[doc(hidden)]
+    #[no_docs]
+    fn is_empty(&self) -> bool {}
+}
+
+fn main() {
+    let foo = Foo(vec![1]);
+    assert_eq!(foo.len(), 1);
+    assert!(!foo.is_empty());
+}
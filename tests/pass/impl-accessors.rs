@@ -0,0 +1,31 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+
+struct Foo(RefCell<Vec<u8>>);
+
+#[delegate(self.0, ref = borrow, mut = get_mut, owned = into_inner)]
+impl Foo {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+    fn into_boxed_slice(self) -> Box<[u8]> {}
+}
+
+struct Bar(RefCell<Vec<u8>>);
+
+#[delegate(self.0, ref = borrow_mut)]
+impl Bar {
+    fn push(&self, value: u8) {}
+    fn pop(&self) -> Option<u8> {}
+}
+
+fn main() {
+    let mut foo = Foo(RefCell::new(vec![1]));
+    assert_eq!(foo.len(), 1);
+    foo.push(2);
+    assert_eq!(foo.len(), 2);
+    assert_eq!(foo.into_boxed_slice().as_ref(), &[1, 2]);
+
+    let bar = Bar(RefCell::new(vec![]));
+    bar.push(1);
+    assert_eq!(bar.pop(), Some(1));
+}
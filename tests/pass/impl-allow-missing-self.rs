@@ -0,0 +1,40 @@
+use delegate_attr::delegate;
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn checksum(bytes: &[u8]) -> u8 {
+        bytes.iter().fold(0, |a, b| a ^ b)
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0, ty = Inner)]
+impl Wrapper {
+    fn len(&self) -> usize {}
+    #[allow_missing_self]
+    fn checksum(bytes: &[u8]) -> u8 {}
+    // self is still delegated as usual
+    #[allow_missing_self]
+    #[call(len)]
+    fn size(&self) -> usize {}
+}
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[allow_missing_self]
+    #[call(Inner::checksum)]
+    fn sum(bytes: &[u8]) -> u8 {}
+}
+
+fn main() {
+    let wrapper = Wrapper(Inner(vec![1, 2]));
+    assert_eq!(wrapper.len(), 2);
+    assert_eq!(wrapper.size(), 2);
+    assert_eq!(Wrapper::checksum(&[1, 3]), 2);
+    assert_eq!(Wrapper::sum(&[1, 3]), 2);
+}
@@ -0,0 +1,33 @@
+use delegate_attr::delegate;
+
+struct Name(String);
+
+#[delegate(self.0, as_ref = str, as_mut = str)]
+impl Name {
+    fn len(&self) -> usize {}
+    fn to_uppercase(&self) -> String {}
+    fn starts_with(&self, prefix: &str) -> bool {}
+    fn make_ascii_lowercase(&mut self) {}
+    fn into_bytes(self) -> Vec<u8> {}
+}
+
+struct Boxed(Box<Vec<u8>>);
+
+#[delegate(self.0, as_ref, as_mut)]
+impl Boxed {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+}
+
+fn main() {
+    let mut name = Name("Foo".to_owned());
+    assert_eq!(name.len(), 3);
+    assert_eq!(name.to_uppercase(), "FOO");
+    assert!(name.starts_with("F"));
+    name.make_ascii_lowercase();
+    assert_eq!(name.into_bytes(), b"foo");
+
+    let mut boxed = Boxed(Box::default());
+    boxed.push(1);
+    assert_eq!(boxed.len(), 1);
+}
@@ -0,0 +1,55 @@
+use delegate_attr::delegate;
+use std::ops::{Add, Index};
+
+struct Iter(std::vec::IntoIter<u8>);
+
+#[delegate(self.0)]
+impl Iterator for Iter {
+    type Item = u8;
+    fn next(&mut self) -> Option<Self::Item> {}
+    fn last(self) -> Option<Self::Item> {}
+}
+
+#[derive(Clone, Copy)]
+struct Meters(u32);
+
+#[delegate(self.0)]
+impl Add for Meters {
+    type Output = u32;
+    // `Self` in arguments is projected, `Self::Output` is returned as is.
+    fn add(self, rhs: Self) -> Self::Output {}
+}
+
+struct Row(Vec<u8>);
+
+#[delegate(self.0)]
+impl Index<usize> for Row {
+    type Output = u8;
+    #[call(index)]
+    fn index(&self, index: usize) -> &Self::Output {}
+}
+
+struct Total(u32);
+
+impl From<u32> for Total {
+    fn from(value: u32) -> Self {
+        Total(value)
+    }
+}
+
+// `Self::Output` is `Self`, but the result is only converted with `#[into]`.
+#[delegate(self.0)]
+impl Add for Total {
+    type Output = Self;
+    #[into]
+    fn add(self, rhs: Self) -> Self::Output {}
+}
+
+fn main() {
+    let mut iter = Iter(vec![1, 2, 3].into_iter());
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.last(), Some(3));
+    assert_eq!(Meters(1) + Meters(2), 3);
+    assert_eq!(Row(vec![4, 5])[1], 5);
+    assert_eq!((Total(1) + Total(2)).0, 3);
+}
@@ -0,0 +1,44 @@
+use delegate_attr::delegate;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    async fn get(&self, index: usize) -> Option<u8> {
+        self.0.get(index).copied()
+    }
+
+    async fn push(&mut self, value: u8) {
+        self.0.push(value);
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+impl Wrapper {
+    async fn get(&self, index: usize) -> Option<u8> {}
+    async fn push(&mut self, value: u8) {}
+
+    #[call(get)]
+    fn get_future(&self, index: usize) -> impl Future<Output = Option<u8>> + '_ {}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = Pin::as_mut(&mut future).poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn main() {
+    let mut wrapper = Wrapper(Inner(vec![1]));
+    block_on(wrapper.push(2));
+    assert_eq!(block_on(wrapper.get(1)), Some(2));
+    assert_eq!(block_on(wrapper.get_future(0)), Some(1));
+}
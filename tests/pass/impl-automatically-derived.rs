@@ -0,0 +1,20 @@
+use delegate_attr::delegate;
+
+struct Name(String);
+
+#[delegate(self.0, automatically_derived)]
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {}
+}
+
+#[delegate(self.0, automatically_derived)]
+#[automatically_derived]
+impl AsRef<[u8]> for Name {
+    fn as_ref(&self) -> &[u8] {}
+}
+
+fn main() {
+    let name = Name("foo".to_owned());
+    assert_eq!(AsRef::<str>::as_ref(&name), "foo");
+    assert_eq!(AsRef::<[u8]>::as_ref(&name), b"foo");
+}
@@ -0,0 +1,47 @@
+use delegate_attr::delegate;
+
+const DEFAULT_PORT: u16 = 443;
+
+mod config {
+    pub const TIMEOUT: u64 = 30;
+    pub static HOST: &str = "localhost";
+}
+
+struct Socket;
+
+impl Socket {
+    fn connect(&self, host: &str, port: u16, timeout: u64) -> String {
+        format!("{}:{} {}s", host, port, timeout)
+    }
+}
+
+struct Client {
+    socket: Socket,
+    port: u16,
+}
+
+impl Client {
+    const RETRIES: u64 = 3;
+}
+
+#[delegate(self.socket)]
+impl Client {
+    #[call_args(host, crate::DEFAULT_PORT, self::config::TIMEOUT)]
+    fn connect(&self, host: &str) -> String {}
+    #[call(connect)]
+    #[call_args(config::HOST, self.port, Self::RETRIES)]
+    fn connect_local(&self) -> String {}
+    #[call(connect)]
+    #[bind(host = crate::config::HOST)]
+    fn connect_to(&self, port: u16, timeout: u64) -> String {}
+}
+
+fn main() {
+    let client = Client {
+        socket: Socket,
+        port: 80,
+    };
+    assert_eq!(client.connect("a"), "a:443 30s");
+    assert_eq!(client.connect_local(), "localhost:80 3s");
+    assert_eq!(client.connect_to(8080, 1), "localhost:8080 1s");
+}
@@ -0,0 +1,46 @@
+use delegate_attr::delegate;
+use std::collections::HashMap;
+
+struct Theme {
+    prefix: String,
+}
+
+struct Renderer;
+
+impl Renderer {
+    fn render(&self, theme: &Theme, text: &str) -> String {
+        format!("{}{}", theme.prefix, text)
+    }
+}
+
+struct Page {
+    renderer: Renderer,
+    theme: Theme,
+    default: u32,
+    counts: HashMap<String, u32>,
+}
+
+#[delegate(self.renderer)]
+impl Page {
+    #[bind(theme = &self.theme)]
+    fn render(&self, text: &str) -> String {}
+
+    #[to(self.counts)]
+    #[call(insert)]
+    #[bind(name = String::from("default"), count = self.default)]
+    fn insert_default(&mut self) -> Option<u32> {}
+}
+
+fn main() {
+    let mut page = Page {
+        renderer: Renderer,
+        theme: Theme {
+            prefix: "> ".to_owned(),
+        },
+        default: 3,
+        counts: HashMap::new(),
+    };
+    assert_eq!(page.render("hi"), "> hi");
+    assert_eq!(page.insert_default(), None);
+    assert_eq!(page.counts["default"], 3);
+}
@@ -0,0 +1,31 @@
+use delegate_attr::delegate;
+
+struct Bytes(Vec<u8>);
+
+#[delegate(self.0)]
+impl Bytes {
+    fn iter(&self) -> impl Iterator<Item = &u8> + '_ {}
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut u8> + '_ {}
+    #[call(iter)]
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a u8> + 'a {}
+}
+
+struct Inlined {
+    bytes: Bytes,
+}
+
+// The forced `#[inline(always)]` doesn't change the signature.
+#[delegate(self.bytes, inline = always)]
+impl Inlined {
+    fn iter(&self) -> impl Iterator<Item = &u8> + '_ {}
+}
+
+fn main() {
+    let mut bytes = Bytes(vec![1, 2]);
+    bytes.iter_mut().for_each(|b| *b *= 2);
+    assert_eq!(bytes.iter().copied().collect::<Vec<_>>(), [2, 4]);
+    assert_eq!(bytes.items().count(), 2);
+
+    let inlined = Inlined { bytes };
+    assert_eq!(inlined.iter().sum::<u8>(), 6);
+}
@@ -0,0 +1,47 @@
+use delegate_attr::delegate;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+trait Fetch {
+    fn fetch(&self, key: u32) -> BoxFuture<'_, String>;
+}
+
+struct Inner;
+
+impl Fetch for Inner {
+    fn fetch(&self, key: u32) -> BoxFuture<'_, String> {
+        Box::pin(async move { key.to_string() })
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+impl Fetch for Wrapper {
+    fn fetch(&self, key: u32) -> BoxFuture<'_, String> {}
+}
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[call(fetch)]
+    fn fetch_spelled_out(&self, key: u32) -> Pin<Box<dyn Future<Output = String> + Send + '_>> {}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn main() {
+    let wrapper = Wrapper(Inner);
+    assert_eq!(block_on(wrapper.fetch(1)), "1");
+    assert_eq!(block_on(wrapper.fetch_spelled_out(2)), "2");
+}
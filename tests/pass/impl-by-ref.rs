@@ -0,0 +1,41 @@
+use delegate_attr::delegate;
+use std::collections::HashMap;
+
+struct Map(HashMap<String, u32>);
+
+#[delegate(self.0)]
+impl Map {
+    fn insert(&mut self, key: String, value: u32) -> Option<u32> {}
+    fn contains_key(&self, #[by_ref] key: String) -> bool {}
+    fn get(&self, key: &str) -> Option<&u32> {}
+}
+
+struct Bytes(Vec<u8>);
+
+impl Bytes {
+    fn fill_from(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+struct Wrapper(Bytes);
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[call(fill_from)]
+    fn into_filled(&self, #[by_mut] mut buf: Vec<u8>) {}
+    fn fill_from(&self, buf: &mut Vec<u8>) {}
+}
+
+fn main() {
+    let mut map = Map(HashMap::new());
+    map.insert("a".to_owned(), 1);
+    assert!(map.contains_key("a".to_owned()));
+    assert_eq!(map.get("a"), Some(&1));
+
+    let wrapper = Wrapper(Bytes(vec![1]));
+    wrapper.into_filled(vec![]);
+    let mut buf = vec![];
+    wrapper.fill_from(&mut buf);
+    assert_eq!(buf, [1]);
+}
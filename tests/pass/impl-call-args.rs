@@ -0,0 +1,37 @@
+use delegate_attr::delegate;
+use std::time::Duration;
+
+struct Socket {
+    retries: u8,
+}
+
+impl Socket {
+    fn connect(&self, host: &str, port: u16, timeout: Duration) -> String {
+        format!("{}:{} {}s", host, port, timeout.as_secs())
+    }
+
+    fn retry(&self, times: u8, host: &str) -> String {
+        format!("{} x{}", host, times)
+    }
+}
+
+struct Client(Socket);
+
+#[delegate(self.0)]
+impl Client {
+    #[call_args(host, 443, Duration::from_secs(30))]
+    fn connect(&self, host: &str) -> String {}
+    #[call(connect)]
+    #[call_args(host, port, Duration::from_secs(5))]
+    fn connect_quick(&self, host: &str, port: u16) -> String {}
+    // `self` refers to the wrapper like in the receiver.
+    #[call_args(self.0.retries, host)]
+    fn retry(&self, host: &str) -> String {}
+}
+
+fn main() {
+    let client = Client(Socket { retries: 3 });
+    assert_eq!(client.connect("a"), "a:443 30s");
+    assert_eq!(client.connect_quick("b", 80), "b:80 5s");
+    assert_eq!(client.retry("c"), "c x3");
+}
@@ -0,0 +1,43 @@
+use delegate_attr::delegate;
+
+struct Callback(fn(u32) -> u32);
+
+#[delegate(self.0)]
+impl Callback {
+    #[call_as_fn]
+    fn call(&self, x: u32) -> u32 {}
+}
+
+struct Handlers {
+    on_event: Box<dyn FnMut(&str) -> usize>,
+    on_close: Option<fn()>,
+}
+
+#[delegate(self.on_event)]
+impl Handlers {
+    #[call_as_fn]
+    fn emit(&mut self, name: &str) -> usize {}
+    #[call_as_fn]
+    #[to(self.on_close.unwrap())]
+    fn close(&self) {}
+}
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn main() {
+    assert_eq!(Callback(double).call(2), 4);
+
+    let mut count = 0;
+    let mut handlers = Handlers {
+        on_event: Box::new(move |name| {
+            count += name.len();
+            count
+        }),
+        on_close: Some(|| {}),
+    };
+    assert_eq!(handlers.emit("ab"), 2);
+    assert_eq!(handlers.emit("c"), 3);
+    handlers.close();
+}
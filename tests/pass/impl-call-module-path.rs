@@ -0,0 +1,58 @@
+use delegate_attr::delegate;
+
+mod internal {
+    pub struct Inner(pub Vec<u8>);
+
+    impl Inner {
+        pub fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        pub fn push(&mut self, value: u8) {
+            self.0.push(value);
+        }
+
+        pub fn into_vec(self) -> Vec<u8> {
+            self.0
+        }
+    }
+
+    pub mod ops {
+        pub fn total(inner: &super::Inner, extra: u32) -> u32 {
+            inner.0.iter().map(|&b| u32::from(b)).sum::<u32>() + extra
+        }
+    }
+}
+
+mod outer {
+    use delegate_attr::delegate;
+
+    pub struct Wrapper(pub crate::internal::Inner);
+
+    #[delegate(self.0)]
+    impl Wrapper {
+        #[call(crate::internal::Inner::len)]
+        pub fn len(&self) -> usize {}
+        #[call(crate::internal::Inner::push)]
+        pub fn push(&mut self, value: u8) {}
+        #[call(super::internal::ops::total)]
+        pub fn total(&self, extra: u32) -> u32 {}
+    }
+}
+
+struct Owned(internal::Inner);
+
+#[delegate(self.0)]
+impl Owned {
+    #[call(self::internal::Inner::into_vec)]
+    fn into_vec(self) -> Vec<u8> {}
+}
+
+fn main() {
+    let mut wrapper = outer::Wrapper(internal::Inner(vec![]));
+    wrapper.push(1);
+    wrapper.push(2);
+    assert_eq!(wrapper.len(), 2);
+    assert_eq!(wrapper.total(10), 13);
+    assert_eq!(Owned(wrapper.0).into_vec(), [1, 2]);
+}
@@ -0,0 +1,48 @@
+use delegate_attr::delegate;
+
+trait Named {
+    fn name(&self) -> &'static str;
+}
+
+trait Labeled {
+    fn name(&self) -> &'static str;
+}
+
+struct Inner;
+
+impl Named for Inner {
+    fn name(&self) -> &'static str {
+        "named"
+    }
+}
+
+impl Labeled for Inner {
+    fn name(&self) -> &'static str {
+        "labeled"
+    }
+}
+
+impl Inner {
+    fn into_label(self) -> String {
+        "label".to_owned()
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[call(<Inner as Named>::name)]
+    fn name(&self) -> &'static str {}
+    #[call(Labeled::name)]
+    fn label(&self) -> &'static str {}
+    #[call(Inner::into_label)]
+    fn into_label(self) -> String {}
+}
+
+fn main() {
+    let wrapper = Wrapper(Inner);
+    assert_eq!(wrapper.name(), "named");
+    assert_eq!(wrapper.label(), "labeled");
+    assert_eq!(wrapper.into_label(), "label");
+}
@@ -0,0 +1,19 @@
+use delegate_attr::delegate;
+
+#[derive(Clone, Copy)]
+struct Small(i32);
+
+#[delegate(self.0 as i64)]
+impl Small {
+    fn pow(&self, exp: u32) -> i64 {}
+    fn signum(self) -> i64 {}
+    #[call(checked_mul)]
+    fn widening_mul(&self, rhs: i64) -> Option<i64> {}
+}
+
+fn main() {
+    let small = Small(i32::MAX);
+    assert_eq!(small.pow(2), (i32::MAX as i64) * (i32::MAX as i64));
+    assert_eq!(small.signum(), 1);
+    assert_eq!(small.widening_mul(2), Some(i32::MAX as i64 * 2));
+}
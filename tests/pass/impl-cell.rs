@@ -0,0 +1,46 @@
+use delegate_attr::delegate;
+use std::cell::Cell;
+
+struct Counter(Cell<u32>);
+
+// `Cell` methods only need `&self`, so no `borrow`/`borrow_mut` is involved.
+#[delegate(self.0)]
+impl Counter {
+    fn get(&self) -> u32 {}
+    fn set(&self, v: u32) {}
+    #[call(replace)]
+    fn swap_value(&self, v: u32) -> u32 {}
+    #[call(take)]
+    fn reset(&self) -> u32 {}
+}
+
+trait Tick {
+    fn tick(&self);
+}
+
+struct Clock(Counter);
+
+impl Tick for Counter {
+    fn tick(&self) {
+        self.set(self.get() + 1);
+    }
+}
+
+#[delegate(self.0)]
+impl Tick for Clock {
+    fn tick(&self) {}
+}
+
+fn main() {
+    let counter = Counter(Cell::new(1));
+    counter.set(2);
+    assert_eq!(counter.get(), 2);
+    assert_eq!(counter.swap_value(5), 2);
+    assert_eq!(counter.reset(), 5);
+    assert_eq!(counter.get(), 0);
+
+    let clock = Clock(counter);
+    clock.tick();
+    clock.tick();
+    assert_eq!(clock.0.get(), 2);
+}
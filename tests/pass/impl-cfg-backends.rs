@@ -0,0 +1,65 @@
+use delegate_attr::delegate;
+
+trait Backend {
+    fn name(&self) -> &'static str;
+}
+
+struct A;
+struct B;
+
+impl Backend for A {
+    fn name(&self) -> &'static str {
+        "a"
+    }
+}
+
+impl Backend for B {
+    fn name(&self) -> &'static str {
+        "b"
+    }
+}
+
+// `cfg(all())` and `cfg(any())` stand for enabled and disabled features.
+struct Store {
+    #[cfg(all())]
+    a: A,
+    #[cfg(any())]
+    b: B,
+}
+
+#[cfg(all())]
+#[delegate(self.a)]
+impl Backend for Store {
+    fn name(&self) -> &'static str {}
+}
+
+#[cfg(any())]
+#[delegate(self.b)]
+impl Backend for Store {
+    fn name(&self) -> &'static str {}
+}
+
+// The same with the attributes in the other order, where `#[cfg]` is kept on the output.
+struct Other {
+    #[cfg(any())]
+    a: A,
+    #[cfg(all())]
+    b: B,
+}
+
+#[delegate(self.a)]
+#[cfg(any())]
+impl Backend for Other {
+    fn name(&self) -> &'static str {}
+}
+
+#[delegate(self.b)]
+#[cfg(all())]
+impl Backend for Other {
+    fn name(&self) -> &'static str {}
+}
+
+fn main() {
+    assert_eq!(Store { a: A }.name(), "a");
+    assert_eq!(Other { b: B }.name(), "b");
+}
@@ -0,0 +1,29 @@
+use delegate_attr::delegate;
+use std::collections::{BTreeMap, BTreeSet};
+
+struct Map(BTreeMap<u8, String>);
+
+#[delegate(self.0)]
+impl Map {
+    #[collect]
+    fn keys(&self) -> Vec<&u8> {}
+    #[collect]
+    #[call(values)]
+    fn value_set(&self) -> BTreeSet<&String> {}
+}
+
+struct Bytes(Vec<u8>);
+
+#[delegate(self.0.iter().copied(), wrap = Bytes)]
+impl Bytes {
+    #[collect]
+    #[call(rev)]
+    fn reversed(&self) -> Self {}
+}
+
+fn main() {
+    let map = Map(vec![(2, "b".to_owned()), (1, "a".to_owned())].into_iter().collect());
+    assert_eq!(map.keys(), [&1, &2]);
+    assert_eq!(map.value_set().len(), 2);
+    assert_eq!(Bytes(vec![1, 2]).reversed().0, [2, 1]);
+}
@@ -0,0 +1,50 @@
+use delegate_attr::delegate;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Level(u8);
+
+#[delegate(self.0, ty = u8)]
+impl Level {
+    const MIN: Self;
+    const MAX: Level;
+    const BITS: u32;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Size {
+    bytes: usize,
+}
+
+impl Size {
+    #[delegate(self.bytes, ty = usize)]
+    const MAX: Self;
+
+    #[delegate(self.bytes, ty = u8, wrap = Size::from_u8)]
+    const MIN: Self;
+
+    const fn from_u8(value: u8) -> Self {
+        Size {
+            bytes: value as usize,
+        }
+    }
+}
+
+const BUFFER: [u8; Level::MAX.0 as usize] = [0; 255];
+
+fn describe(level: Level) -> &'static str {
+    match level {
+        Level::MIN => "min",
+        Level::MAX => "max",
+        _ => "other",
+    }
+}
+
+fn main() {
+    assert_eq!(BUFFER.len(), 255);
+    assert_eq!(Level::BITS, 8);
+    assert_eq!(describe(Level(0)), "min");
+    assert_eq!(describe(Level(255)), "max");
+    assert_eq!(describe(Level(1)), "other");
+    assert_eq!(Size::MAX.bytes, usize::MAX);
+    assert_eq!(Size::MIN.bytes, 0);
+}
@@ -0,0 +1,38 @@
+use delegate_attr::delegate;
+
+struct Inner;
+
+impl Inner {
+    const NAME: &'static str = "inner";
+    const SIZE: usize = 4;
+
+    fn get(&self) -> u8 {
+        1
+    }
+}
+
+trait Named {
+    const NAME: &'static str;
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0, ty = Inner)]
+impl Wrapper {
+    const SIZE: usize;
+    const DOUBLE_SIZE: usize = Self::SIZE * 2;
+
+    fn get(&self) -> u8 {}
+}
+
+#[delegate(self.0, ty = Inner)]
+impl Named for Wrapper {
+    const NAME: &'static str;
+}
+
+fn main() {
+    assert_eq!(Wrapper::SIZE, 4);
+    assert_eq!(Wrapper::DOUBLE_SIZE, 8);
+    assert_eq!(<Wrapper as Named>::NAME, "inner");
+    assert_eq!(Wrapper(Inner).get(), 1);
+}
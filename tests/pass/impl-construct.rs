@@ -0,0 +1,34 @@
+use delegate_attr::delegate;
+use std::string::FromUtf8Error;
+
+struct Stack<T>(Vec<T>);
+
+#[delegate(self.0, ty = Vec<T>)]
+impl<T> Stack<T> {
+    fn new() -> Self {}
+    fn with_capacity(capacity: usize) -> Self {}
+    fn len(&self) -> usize {}
+    fn capacity(&self) -> usize {}
+    fn push(&mut self, value: T) {}
+}
+
+struct Text(String);
+
+#[delegate(self.0, ty = String)]
+impl Text {
+    fn new() -> Self {}
+    fn from_utf8(bytes: Vec<u8>) -> Result<Self, FromUtf8Error> {}
+    fn as_str(&self) -> &str {}
+}
+
+fn main() {
+    let mut stack = Stack::new();
+    stack.push(1);
+    assert_eq!(stack.len(), 1);
+    let stack = Stack::<u8>::with_capacity(8);
+    assert!(stack.capacity() >= 8);
+
+    assert_eq!(Text::new().as_str(), "");
+    assert_eq!(Text::from_utf8(b"abc".to_vec()).unwrap().as_str(), "abc");
+    assert!(Text::from_utf8(vec![0xff]).is_err());
+}
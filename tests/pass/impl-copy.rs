@@ -0,0 +1,25 @@
+use delegate_attr::delegate;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Id(u32);
+
+#[delegate(self.0)]
+impl Id {
+    fn count_ones(self) -> u32 {}
+    fn is_power_of_two(self) -> bool {}
+    #[call(wrapping_add)]
+    fn offset(self, rhs: u32) -> Self {}
+}
+
+fn main() {
+    let id = Id(6);
+    let mut total = 0;
+    for _ in 0..3 {
+        // `id` is copied into each call.
+        total += id.count_ones();
+    }
+    assert_eq!(total, 6);
+    assert!(!id.is_power_of_two());
+    assert_eq!(id.offset(1), Id(7));
+    assert_eq!(id, Id(6));
+}
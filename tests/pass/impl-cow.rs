@@ -0,0 +1,29 @@
+use delegate_attr::delegate;
+use std::borrow::Cow;
+
+struct Text(Cow<'static, str>);
+
+#[delegate(self.0)]
+impl Text {
+    // `str` methods via `Deref`
+    fn len(&self) -> usize {}
+    fn to_uppercase(&self) -> String {}
+    // methods of `Cow` itself, including by-value ones
+    fn to_mut(&mut self) -> &mut String {}
+    fn into_owned(self) -> String {}
+}
+
+// Consume the `Cow` into an owned value to call by-value methods of the owned type.
+#[delegate(self.0.into_owned())]
+impl Text {
+    fn into_bytes(self) -> Vec<u8> {}
+}
+
+fn main() {
+    let mut text = Text(Cow::Borrowed("abc"));
+    assert_eq!(text.len(), 3);
+    assert_eq!(text.to_uppercase(), "ABC");
+    text.to_mut().push('d');
+    assert_eq!(text.into_owned(), "abcd");
+    assert_eq!(Text(Cow::Borrowed("ab")).into_bytes(), b"ab");
+}
@@ -0,0 +1,70 @@
+use delegate_attr::delegate;
+use std::ops::{Deref, DerefMut};
+
+/// A smart pointer standing in for third-party ones, like a guard.
+struct Guard<T> {
+    value: T,
+}
+
+impl<T> Guard<T> {
+    fn new(value: T) -> Self {
+        Guard { value }
+    }
+}
+
+impl<T> Deref for Guard<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Guard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+struct Wrapper(Guard<Vec<u8>>);
+
+#[delegate(self.0)]
+impl Wrapper {
+    fn len(&self) -> usize {}
+    fn first(&self) -> Option<&u8> {}
+    fn push(&mut self, value: u8) {}
+}
+
+struct Nested(Guard<Guard<String>>);
+
+#[delegate(self.0)]
+impl Nested {
+    fn as_str(&self) -> &str {}
+    fn push_str(&mut self, value: &str) {}
+}
+
+// A temporary guard per call, like `arc_swap::ArcSwap::load`.
+struct Loaded(Vec<u8>);
+
+impl Loaded {
+    fn load(&self) -> Guard<&[u8]> {
+        Guard::new(&self.0)
+    }
+}
+
+#[delegate(self.load())]
+impl Loaded {
+    fn is_empty(&self) -> bool {}
+}
+
+fn main() {
+    let mut wrapper = Wrapper(Guard::new(vec![]));
+    wrapper.push(1);
+    assert_eq!(wrapper.len(), 1);
+    assert_eq!(wrapper.first(), Some(&1));
+
+    let mut nested = Nested(Guard::new(Guard::new(String::new())));
+    nested.push_str("foo");
+    assert_eq!(nested.as_str(), "foo");
+
+    assert!(Loaded(vec![]).is_empty());
+}
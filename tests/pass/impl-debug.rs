@@ -0,0 +1,19 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0, debug)]
+impl Foo {
+    fn len(&self) -> usize {}
+}
+
+impl Foo {
+    #[delegate(self.0, inline = never, debug)]
+    fn first(&self) -> Option<&u8> {}
+}
+
+fn main() {
+    let foo = Foo(vec![1]);
+    assert_eq!(foo.len(), 1);
+    assert_eq!(foo.first(), Some(&1));
+}
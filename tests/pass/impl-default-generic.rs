@@ -0,0 +1,37 @@
+use delegate_attr::delegate;
+
+struct Foo<T = u8>(Vec<T>);
+
+#[delegate(self.0)]
+impl<T> Foo<T> {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: T) {}
+}
+
+// The default is used when the type is spelled without arguments.
+#[delegate(self.0)]
+impl Foo {
+    fn first(&self) -> Option<&u8> {}
+}
+
+struct Pair<A, B = A>(A, B);
+
+#[delegate(self.1)]
+impl<A, B: Clone> Pair<A, B> {
+    #[call(clone)]
+    fn second(&self) -> B {}
+}
+
+fn main() {
+    let mut foo: Foo = Foo(vec![]);
+    foo.push(1);
+    assert_eq!(foo.len(), 1);
+    assert_eq!(foo.first(), Some(&1));
+
+    let mut strings = Foo::<String>(vec![]);
+    strings.push("a".to_owned());
+    assert_eq!(strings.len(), 1);
+
+    let pair: Pair<u8> = Pair(1, 2);
+    assert_eq!(pair.second(), 2);
+}
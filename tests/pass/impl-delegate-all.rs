@@ -0,0 +1,69 @@
+use delegate_attr::delegate_all;
+use std::cell::RefCell;
+
+trait Draw {
+    fn draw(&self, log: &RefCell<Vec<String>>);
+}
+
+struct Circle;
+struct Square;
+
+impl Draw for Circle {
+    fn draw(&self, log: &RefCell<Vec<String>>) {
+        log.borrow_mut().push("circle".to_owned());
+    }
+}
+
+impl Draw for Square {
+    fn draw(&self, log: &RefCell<Vec<String>>) {
+        log.borrow_mut().push("square".to_owned());
+    }
+}
+
+struct Pair<A: Draw, B: Draw>(A, B);
+
+#[delegate_all(self.0, self.1)]
+impl<A: Draw, B: Draw> Draw for Pair<A, B> {
+    fn draw(&self, log: &RefCell<Vec<String>>) {}
+}
+
+struct Logs {
+    a: Vec<u8>,
+    b: Vec<u8>,
+}
+
+impl Logs {
+    #[delegate_all(self.b, self.a)]
+    fn push(&mut self, value: u8) {}
+}
+
+mod both {
+    use delegate_attr::delegate_all;
+
+    pub struct Both(pub Vec<u8>, pub Vec<u8>);
+
+    #[delegate_all(self.0, self.1)]
+    #[rename(push = push_both, clear = clear_both)]
+    #[vis(pub)]
+    impl Both {
+        fn push_both(&mut self, value: u8) {}
+        fn clear_both(&mut self) {}
+    }
+}
+
+fn main() {
+    let log = RefCell::new(vec![]);
+    Pair(Circle, Square).draw(&log);
+    Pair(Square, Circle).draw(&log);
+    assert_eq!(*log.borrow(), ["circle", "square", "square", "circle"]);
+
+    let mut logs = Logs { a: vec![], b: vec![] };
+    logs.push(1);
+    assert_eq!((logs.a, logs.b), (vec![1], vec![1]));
+
+    let mut both = both::Both(vec![1], vec![]);
+    both.push_both(2);
+    assert_eq!((&both.0, &both.1), (&vec![1, 2], &vec![2]));
+    both.clear_both();
+    assert!(both.0.is_empty() && both.1.is_empty());
+}
@@ -0,0 +1,58 @@
+use delegate_attr::delegate;
+use std::sync::{Arc, Mutex};
+
+struct Nested(Box<Box<Vec<u8>>>);
+
+#[delegate(**self.0)]
+impl Nested {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+    fn into_boxed_slice(self) -> Box<[u8]> {}
+}
+
+struct Borrowed(Box<Box<Vec<u8>>>);
+
+#[delegate(&**self.0)]
+impl Borrowed {
+    fn len(&self) -> usize {}
+    fn is_empty(&self) -> bool {}
+}
+
+struct Shared(Arc<Mutex<Box<Vec<u8>>>>);
+
+#[delegate(*self.0.lock().unwrap())]
+impl Shared {
+    fn len(&self) -> usize {}
+    fn push(&self, value: u8) {}
+}
+
+struct Overridden {
+    values: Box<Vec<u8>>,
+}
+
+#[delegate(self.values)]
+impl Overridden {
+    #[to(&*self.values)]
+    #[call(first)]
+    fn front(&self) -> Option<&u8> {}
+}
+
+fn main() {
+    let mut nested = Nested(Box::new(Box::new(vec![])));
+    nested.push(1);
+    assert_eq!(nested.len(), 1);
+    assert_eq!(&*nested.into_boxed_slice(), [1]);
+
+    let borrowed = Borrowed(Box::new(Box::new(vec![1, 2])));
+    assert_eq!(borrowed.len(), 2);
+    assert!(!borrowed.is_empty());
+
+    let shared = Shared(Arc::new(Mutex::new(Box::new(vec![]))));
+    shared.push(1);
+    assert_eq!(shared.len(), 1);
+
+    let overridden = Overridden {
+        values: Box::new(vec![3]),
+    };
+    assert_eq!(overridden.front(), Some(&3));
+}
@@ -0,0 +1,36 @@
+#![deny(unused_must_use)]
+
+use delegate_attr::delegate;
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    #[must_use]
+    fn push(&mut self, value: u8) -> usize {
+        self.0.push(value);
+        self.0.len()
+    }
+
+    fn pop(&mut self) -> Result<u8, ()> {
+        self.0.pop().ok_or(())
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[discard]
+    fn push(&mut self, value: u8) {}
+    #[discard]
+    #[call(pop)]
+    fn drop_last(&mut self) {}
+}
+
+fn main() {
+    let mut wrapper = Wrapper(Inner(vec![]));
+    wrapper.push(1);
+    wrapper.push(2);
+    wrapper.drop_last();
+    assert_eq!(wrapper.0 .0, [1]);
+}
@@ -0,0 +1,41 @@
+use delegate_attr::delegate;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+thread_local! {
+    static LOG: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+fn log(name: &'static str) {
+    LOG.with(|log| log.borrow_mut().push(name));
+}
+
+struct Connection {
+    closed: Rc<Cell<u32>>,
+}
+
+impl Connection {
+    fn close(&mut self) {
+        self.closed.set(self.closed.get() + 1);
+    }
+}
+
+struct Handle(Connection);
+
+// `on_call` runs before the target.
+#[delegate(self.0, on_call = log)]
+impl Drop for Handle {
+    #[call(close)]
+    fn drop(&mut self) {}
+}
+
+fn main() {
+    let closed = Rc::new(Cell::new(0));
+    let handle = Handle(Connection {
+        closed: closed.clone(),
+    });
+    assert_eq!(closed.get(), 0);
+    drop(handle);
+    assert_eq!(closed.get(), 1);
+    LOG.with(|log| assert_eq!(*log.borrow(), ["drop"]));
+}
@@ -0,0 +1,66 @@
+use delegate_attr::delegate;
+
+trait Component {
+    fn name(&self) -> String;
+    fn resize(&mut self, width: u32);
+    fn width(&self) -> u32;
+    fn area(&self, height: u32) -> u32 {
+        self.width() * height
+    }
+}
+
+struct Label(u32);
+
+impl Component for Label {
+    fn name(&self) -> String {
+        "label".to_owned()
+    }
+    fn resize(&mut self, width: u32) {
+        self.0 = width;
+    }
+    fn width(&self) -> u32 {
+        self.0
+    }
+}
+
+struct Button(u32);
+
+impl Component for Button {
+    fn name(&self) -> String {
+        "button".to_owned()
+    }
+    fn resize(&mut self, width: u32) {
+        self.0 = width * 2;
+    }
+    fn width(&self) -> u32 {
+        self.0
+    }
+    fn area(&self, _height: u32) -> u32 {
+        0
+    }
+}
+
+struct Widget(Box<dyn Component>);
+
+// Inherent methods, dispatched dynamically through the boxed trait object.
+#[delegate(self.0)]
+impl Widget {
+    fn name(&self) -> String {}
+    fn resize(&mut self, width: u32) {}
+    #[inline_always]
+    fn width(&self) -> u32 {}
+    fn area(&self, height: u32) -> u32 {}
+}
+
+fn main() {
+    let mut widgets = vec![Widget(Box::new(Label(1))), Widget(Box::new(Button(1)))];
+    for widget in &mut widgets {
+        widget.resize(3);
+    }
+    let names = widgets.iter().map(Widget::name).collect::<Vec<_>>();
+    assert_eq!(names, ["label", "button"]);
+    assert_eq!(widgets[0].width(), 3);
+    assert_eq!(widgets[1].width(), 6);
+    assert_eq!(widgets[0].area(2), 6);
+    assert_eq!(widgets[1].area(2), 0);
+}
@@ -0,0 +1,70 @@
+use delegate_attr::delegate;
+
+trait Backend {
+    fn name(&self) -> &str;
+    fn store(&mut self, value: u8);
+    fn into_values(self) -> Vec<u8>;
+}
+
+struct Memory(Vec<u8>);
+struct Disk(Vec<u8>);
+
+impl Backend for Memory {
+    fn name(&self) -> &str {
+        "memory"
+    }
+    fn store(&mut self, value: u8) {
+        self.0.push(value);
+    }
+    fn into_values(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl Backend for Disk {
+    fn name(&self) -> &str {
+        "disk"
+    }
+    fn store(&mut self, value: u8) {
+        self.0.insert(0, value);
+    }
+    fn into_values(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+enum AnyBackend {
+    Memory(Memory),
+    Disk(Disk),
+}
+
+#[delegate(enum(Memory, Disk))]
+impl Backend for AnyBackend {
+    fn name(&self) -> &str {}
+    fn store(&mut self, value: u8) {}
+    fn into_values(self) -> Vec<u8> {}
+}
+
+#[delegate(enum(Memory, Disk,))]
+impl AnyBackend {
+    #[call(name)]
+    #[into]
+    fn name_owned(&self) -> String {}
+    #[to(vec![0])]
+    #[call(len)]
+    fn zero_len(&self) -> usize {}
+}
+
+fn main() {
+    let mut memory = AnyBackend::Memory(Memory(vec![]));
+    let mut disk = AnyBackend::Disk(Disk(vec![]));
+    for value in 1..3 {
+        memory.store(value);
+        disk.store(value);
+    }
+    assert_eq!(memory.name(), "memory");
+    assert_eq!(disk.name_owned(), "disk");
+    assert_eq!(disk.zero_len(), 1);
+    assert_eq!(memory.into_values(), [1, 2]);
+    assert_eq!(disk.into_values(), [2, 1]);
+}
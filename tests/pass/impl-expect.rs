@@ -0,0 +1,42 @@
+use delegate_attr::delegate;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+
+struct Slot(Option<Vec<u8>>);
+
+#[delegate(self.0, expect = "slot is empty")]
+impl Slot {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+}
+
+struct Shared(Result<Mutex<Vec<u8>>, String>);
+
+#[delegate(self.0, expect = "shared is unavailable")]
+impl Shared {
+    #[propagate]
+    #[call(is_poisoned)]
+    fn try_is_poisoned(&self) -> Result<bool, &String> {}
+}
+
+fn message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+fn main() {
+    let mut slot = Slot(Some(vec![]));
+    slot.push(1);
+    assert_eq!(slot.len(), 1);
+
+    panic::set_hook(Box::new(|_| {}));
+    let slot = Slot(None);
+    let payload = panic::catch_unwind(AssertUnwindSafe(|| slot.len())).unwrap_err();
+    assert_eq!(message(payload), "slot is empty");
+
+    let shared = Shared(Err("closed".to_owned()));
+    assert_eq!(shared.try_is_poisoned(), Err(&"closed".to_owned()));
+}
@@ -0,0 +1,50 @@
+use delegate_attr::delegate;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn poll_once<F: Future>(future: F) -> Poll<F::Output> {
+    let waker = Waker::from(Arc::new(Noop));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    future.as_mut().poll(&mut cx)
+}
+
+// `Ready` is `Unpin`, so no unsafe is needed.
+struct Wrap(future::Ready<u8>);
+
+#[delegate(self.0, ty = future::Ready<u8>)]
+impl Future for Wrap {
+    type Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {}
+}
+
+// `F` may not be `Unpin`, but it's never moved out of a pinned `Pinned`.
+struct Pinned<F> {
+    future: F,
+}
+
+#[delegate(self.future, ty = F, unsafe_pin)]
+impl<F: Future> Future for Pinned<F> {
+    type Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {}
+}
+
+fn main() {
+    assert_eq!(poll_once(Wrap(future::ready(1))), Poll::Ready(1));
+    let pinned = Pinned {
+        future: async { 2 },
+    };
+    assert_eq!(poll_once(pinned), Poll::Ready(2));
+    let pending = Pinned {
+        future: future::pending::<u8>(),
+    };
+    assert_eq!(poll_once(pending), Poll::Pending);
+}
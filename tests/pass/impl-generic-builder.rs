@@ -0,0 +1,38 @@
+use delegate_attr::delegate;
+
+#[derive(Default)]
+struct Builder {
+    names: Vec<String>,
+    size: usize,
+}
+
+impl Builder {
+    fn with<T: Into<String>>(mut self, name: T) -> Self {
+        self.names.push(name.into());
+        self
+    }
+
+    fn size<const N: usize>(mut self) -> Self {
+        self.size = N;
+        self
+    }
+}
+
+#[derive(Default)]
+struct Wrapper(Builder);
+
+#[delegate(self.0)]
+impl Wrapper {
+    fn with<T>(self, name: T) -> Self
+    where
+        T: Into<String>,
+    {
+    }
+    fn size<const N: usize>(self) -> Self {}
+}
+
+fn main() {
+    let wrapper = Wrapper::default().with("a").with(String::from("b")).size::<2>();
+    assert_eq!(wrapper.0.names, ["a", "b"]);
+    assert_eq!(wrapper.0.size, 2);
+}
@@ -0,0 +1,37 @@
+use delegate_attr::delegate;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+struct Map<K, V>(HashMap<K, V>);
+
+#[delegate(self.0)]
+impl<K: Eq + Hash, V> Map<K, V> {
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+    }
+    fn contains_key<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+    }
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+    }
+    fn insert(&mut self, key: K, value: V) -> Option<V> {}
+}
+
+fn main() {
+    let mut map = Map(HashMap::new());
+    map.insert("a".to_owned(), 1);
+    assert_eq!(map.get("a"), Some(&1));
+    assert!(map.contains_key("a"));
+    *map.get_mut("a").unwrap() += 1;
+    assert_eq!(map.get(&"a".to_owned()), Some(&2));
+}
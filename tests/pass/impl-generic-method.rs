@@ -0,0 +1,47 @@
+use delegate_attr::delegate;
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    fn chunk<const N: usize>(&self) -> [u8; N] {
+        let mut chunk = [0; N];
+        chunk.copy_from_slice(&self.0[..N]);
+        chunk
+    }
+
+    fn parse<T: std::str::FromStr>(&self) -> Option<T> {
+        std::str::from_utf8(&self.0).ok()?.parse().ok()
+    }
+
+    fn map<U>(&self, f: impl Fn(&[u8]) -> U) -> U {
+        f(&self.0)
+    }
+
+    fn sum<T: From<u8> + std::ops::Add<Output = T>, F: Fn(T, T) -> T>(&self, f: F) -> T {
+        self.0.iter().map(|&b| T::from(b)).fold(T::from(0), f)
+    }
+
+    fn is_len<T: Into<u64>, U: Into<T>>(&self, len: U) -> bool {
+        len.into().into() == self.0.len() as u64
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+impl Wrapper {
+    fn chunk<const N: usize>(&self) -> [u8; N] {}
+    fn parse<'a, T: std::str::FromStr>(&'a self) -> Option<T> {}
+    fn map<U, F: Fn(&[u8]) -> U>(&self, f: F) -> U {}
+    fn sum<T: From<u8> + std::ops::Add<Output = T>, F: Fn(T, T) -> T>(&self, f: F) -> T {}
+    fn is_len<T: Into<u64>, U: Into<T>>(&self, len: U) -> bool {}
+}
+
+fn main() {
+    let wrapper = Wrapper(Inner(b"123".to_vec()));
+    assert_eq!(wrapper.chunk::<2>(), *b"12");
+    assert_eq!(wrapper.parse::<u32>(), Some(123));
+    assert_eq!(wrapper.map(|bytes| bytes.len()), 3);
+    assert_eq!(wrapper.sum::<u32, _>(|a, b| a + b), 150);
+    assert!(wrapper.is_len::<u32, _>(3u8));
+}
@@ -0,0 +1,37 @@
+use delegate_attr::delegate;
+
+struct Foo {
+    inner: Vec<u8>,
+}
+
+impl Foo {
+    fn inner(&self) -> &Vec<u8> {
+        &self.inner
+    }
+    fn inner_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.inner
+    }
+    fn into_inner(self) -> Vec<u8> {
+        self.inner
+    }
+}
+
+#[delegate(self.inner())]
+impl Foo {
+    fn len(&self) -> usize {}
+}
+
+#[delegate(self, ref = inner, mut = inner_mut, owned = into_inner)]
+impl Foo {
+    fn is_empty(&self) -> bool {}
+    fn push(&mut self, value: u8) {}
+    fn into_boxed_slice(self) -> Box<[u8]> {}
+}
+
+fn main() {
+    let mut foo = Foo { inner: vec![1] };
+    assert_eq!(foo.len(), 1);
+    foo.push(2);
+    assert!(!foo.is_empty());
+    assert_eq!(foo.into_boxed_slice().as_ref(), &[1, 2]);
+}
@@ -0,0 +1,71 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+// Methods returning `&Self` return the wrapper, not a reference through the guard.
+struct Log(RefCell<Vec<u8>>);
+
+#[delegate(self.0, mut)]
+impl Log {
+    fn push(&self, value: u8) -> &Self {}
+}
+
+struct Shared {
+    v: Mutex<Vec<u8>>,
+}
+
+#[delegate(self.v.lock().unwrap())]
+impl Shared {
+    fn push(&self, x: u8) -> &Self {}
+    fn len(&self) -> usize {}
+}
+
+// A `borrow()` which returns a plain reference rather than a guard.
+struct Frozen(Rc<String>);
+
+impl Frozen {
+    fn borrow(&self) -> &String {
+        &self.0
+    }
+}
+
+struct Name(Frozen);
+
+#[delegate(self.0.borrow(), no_guard)]
+impl Name {
+    fn as_str(&self) -> &str {}
+}
+
+// References inside `Fn` arguments don't borrow from `self`.
+struct Matcher;
+
+impl Matcher {
+    fn matcher(&self) -> Box<dyn Fn(&str) -> bool> {
+        Box::new(|s| s.is_empty())
+    }
+}
+
+struct Matchers(RefCell<Matcher>);
+
+#[delegate(self.0.borrow())]
+impl Matchers {
+    fn matcher(&self) -> Box<dyn Fn(&str) -> bool> {}
+}
+
+fn main() {
+    let log = Log(RefCell::new(vec![]));
+    log.push(1).push(2);
+    assert_eq!(*log.0.borrow(), [1, 2]);
+
+    let shared = Shared {
+        v: Mutex::new(vec![]),
+    };
+    assert_eq!(shared.push(1).push(2).len(), 2);
+
+    let name = Name(Frozen(Rc::new("name".to_owned())));
+    assert_eq!(name.as_str(), "name");
+
+    let matchers = Matchers(RefCell::new(Matcher));
+    assert!(matchers.matcher()(""));
+}
@@ -0,0 +1,45 @@
+use delegate_attr::delegate;
+use std::collections::HashMap;
+
+struct Grid(Vec<Vec<u8>>);
+
+#[delegate(self.0[0])]
+impl Grid {
+    fn len(&self) -> usize {}
+    fn first(&self) -> Option<&u8> {}
+    fn push(&mut self, value: u8) {}
+}
+
+const KEY: &str = "main";
+
+struct Registry {
+    lists: HashMap<&'static str, Vec<u8>>,
+    row: usize,
+}
+
+#[delegate(self.lists[KEY])]
+impl Registry {
+    fn is_empty(&self) -> bool {}
+    #[to(self.lists.get_mut(KEY).unwrap())]
+    fn push(&mut self, value: u8) {}
+    // The index can use other fields of `self`.
+    #[to(self.lists[KEY][self.row])]
+    #[call(clone)]
+    fn current(&self) -> u8 {}
+}
+
+fn main() {
+    let mut grid = Grid(vec![vec![1], vec![2, 3]]);
+    grid.push(4);
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.first(), Some(&1));
+
+    let mut registry = Registry {
+        lists: vec![(KEY, vec![])].into_iter().collect(),
+        row: 1,
+    };
+    assert!(registry.is_empty());
+    registry.push(1);
+    registry.push(2);
+    assert_eq!(registry.current(), 2);
+}
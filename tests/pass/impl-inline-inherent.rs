@@ -0,0 +1,24 @@
+use delegate_attr::delegate;
+
+struct Bytes(std::vec::IntoIter<u8>);
+
+#[delegate(self.0, inline = inherent)]
+impl Bytes {
+    fn len(&self) -> usize {}
+    fn as_slice(&self) -> &[u8] {}
+}
+
+#[delegate(self.0, inline = inherent)]
+impl Iterator for Bytes {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {}
+    fn size_hint(&self) -> (usize, Option<usize>) {}
+}
+
+fn main() {
+    let mut bytes = Bytes(vec![1, 2].into_iter());
+    assert_eq!(bytes.len(), 2);
+    assert_eq!(bytes.next(), Some(1));
+    assert_eq!(bytes.as_slice(), [2]);
+    assert_eq!(bytes.size_hint(), (1, Some(1)));
+}
@@ -0,0 +1,28 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0, inline = never)]
+impl Foo {
+    fn len(&self) -> usize {}
+    #[inline_always]
+    fn is_empty(&self) -> bool {}
+}
+
+#[delegate(self.0, inline = always)]
+impl Foo {
+    #[no_inline]
+    fn capacity(&self) -> usize {}
+    fn first(&self) -> Option<&u8> {}
+    #[cold]
+    fn last(&self) -> Option<&u8> {}
+}
+
+fn main() {
+    let foo = Foo(vec![1]);
+    assert_eq!(foo.len(), 1);
+    assert!(!foo.is_empty());
+    assert!(foo.capacity() >= 1);
+    assert_eq!(foo.first(), Some(&1));
+    assert_eq!(foo.last(), Some(&1));
+}
@@ -0,0 +1,36 @@
+use delegate_attr::delegate;
+use std::collections::HashMap;
+
+struct Env(HashMap<String, String>);
+
+#[delegate(self.0)]
+impl Env {
+    #[into_args]
+    fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {}
+}
+
+struct Total(u64);
+
+impl Total {
+    fn add(&mut self, a: u64, b: u64) {
+        self.0 += a + b;
+    }
+}
+
+struct Wrapper(Total);
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[into_args]
+    fn add<A: Into<u64>, B: Into<u64>>(&mut self, a: A, b: B) {}
+}
+
+fn main() {
+    let mut env = Env(HashMap::new());
+    assert_eq!(env.insert("a", String::from("1")), None);
+    assert_eq!(env.insert(String::from("a"), "2"), Some("1".to_owned()));
+
+    let mut total = Wrapper(Total(0));
+    total.add(1u8, 2u32);
+    assert_eq!(total.0 .0, 3);
+}
@@ -0,0 +1,22 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+
+struct Foo(RefCell<Vec<u32>>);
+
+#[delegate(self.0.borrow())]
+impl Foo {
+    #[into]
+    fn len(&self) -> usize {}
+}
+
+#[delegate(self.0.borrow_mut())]
+impl Foo {
+    #[into]
+    fn pop(&self) -> Option<u32> {}
+}
+
+fn main() {
+    let foo = Foo(RefCell::new(vec![1, 2]));
+    assert_eq!(foo.len(), 2);
+    assert_eq!(foo.pop(), Some(2));
+}
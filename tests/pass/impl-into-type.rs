@@ -0,0 +1,30 @@
+use delegate_attr::delegate;
+use std::rc::Rc;
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    fn evens(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.iter().copied().filter(|x| x % 2 == 0)
+    }
+    fn first(&self) -> u8 {
+        self.0[0]
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[collect]
+    #[into(Vec<u8>)]
+    fn evens(&self) -> Rc<[u8]> {}
+    #[into(u8)]
+    fn first(&self) -> u64 {}
+}
+
+fn main() {
+    let wrapper = Wrapper(Inner(vec![1, 2, 3, 4]));
+    assert_eq!(&*wrapper.evens(), [2, 4]);
+    assert_eq!(wrapper.first(), 1);
+}
@@ -0,0 +1,24 @@
+use delegate_attr::delegate;
+use std::iter::{Filter, Map};
+use std::vec::IntoIter;
+
+struct Iter(IntoIter<u8>);
+
+#[delegate(self.0)]
+impl Iter {
+    fn map<B, F: FnMut(u8) -> B>(self, f: F) -> Map<IntoIter<u8>, F> {}
+    fn filter<P>(self, predicate: P) -> Filter<IntoIter<u8>, P>
+    where
+        P: FnMut(&u8) -> bool,
+    {
+    }
+    fn sum<S: std::iter::Sum<u8>>(self) -> S {}
+}
+
+fn main() {
+    let doubled = Iter(vec![1, 2, 3].into_iter()).map(|x| x * 2);
+    assert_eq!(doubled.collect::<Vec<_>>(), [2, 4, 6]);
+    let odd = Iter(vec![1, 2, 3].into_iter()).filter(|x| x % 2 == 1);
+    assert_eq!(odd.collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(Iter(vec![1, 2, 3].into_iter()).sum::<u8>(), 6);
+}
@@ -0,0 +1,59 @@
+use delegate_attr::delegate;
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn push(&mut self, value: u8) {
+        self.0.push(value);
+    }
+    fn first(&self) -> Option<&u8> {
+        self.0.first()
+    }
+}
+
+struct View<'a>(&'a mut Inner);
+
+#[delegate(self.0)]
+impl<'a> View<'a> {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+    fn first(&self) -> Option<&u8> {}
+}
+
+// By-value methods can consume the reference itself.
+#[delegate(self.0)]
+impl<'a> View<'a> {
+    #[call(push)]
+    fn finish(self, value: u8) {}
+}
+
+struct Ref<'a, T: 'a>(&'a [T]);
+
+#[delegate(self.0)]
+impl<'a, T> Ref<'a, T>
+where
+    T: Copy,
+{
+    fn len(&self) -> usize {}
+    fn first(&self) -> Option<&T> {}
+    fn iter(self) -> std::slice::Iter<'a, T> {}
+}
+
+fn main() {
+    let mut inner = Inner(vec![1]);
+    let mut view = View(&mut inner);
+    view.push(2);
+    assert_eq!(view.len(), 2);
+    assert_eq!(view.first(), Some(&1));
+    view.finish(3);
+    assert_eq!(inner.len(), 3);
+
+    let slice = [1, 2, 3];
+    let r = Ref(&slice);
+    assert_eq!(r.len(), 3);
+    assert_eq!(r.first(), Some(&1));
+    assert_eq!(r.iter().sum::<i32>(), 6);
+}
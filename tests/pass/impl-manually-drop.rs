@@ -0,0 +1,22 @@
+use delegate_attr::delegate;
+use std::mem::ManuallyDrop;
+
+struct Wrapper(ManuallyDrop<Vec<u8>>);
+
+#[delegate(self.0)]
+impl Wrapper {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+}
+
+#[delegate(ManuallyDrop::into_inner(self.0))]
+impl Wrapper {
+    fn into_boxed_slice(self) -> Box<[u8]> {}
+}
+
+fn main() {
+    let mut wrapper = Wrapper(ManuallyDrop::new(vec![1]));
+    wrapper.push(2);
+    assert_eq!(wrapper.len(), 2);
+    assert_eq!(&*wrapper.into_boxed_slice(), [1, 2]);
+}
@@ -0,0 +1,20 @@
+#![deny(unused_must_use)]
+
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0, must_use_all)]
+impl Foo {
+    fn len(&self) -> usize {}
+    #[must_use = "check whether it's empty"]
+    fn is_empty(&self) -> bool {}
+    fn push(&mut self, value: u8) {}
+}
+
+fn main() {
+    let mut foo = Foo(vec![]);
+    foo.push(1);
+    assert_eq!(foo.len(), 1);
+    assert!(!foo.is_empty());
+}
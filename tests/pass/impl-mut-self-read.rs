@@ -0,0 +1,31 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+
+struct Log(RefCell<Vec<String>>, usize);
+
+#[delegate(self.0.borrow_mut())]
+impl Log {
+    fn push(&mut self, line: String) {}
+    // Only reads, so an immutable borrow is enough even though the method takes `&mut self`.
+    #[to(self.0.borrow())]
+    fn len(&mut self) -> usize {}
+    #[to(self.0.borrow())]
+    fn is_empty(&mut self) -> bool {}
+}
+
+impl Log {
+    fn record(&mut self, line: &str) -> usize {
+        self.push(line.to_owned());
+        self.1 += 1;
+        self.len()
+    }
+}
+
+fn main() {
+    let mut log = Log(RefCell::new(vec![]), 0);
+    assert!(log.is_empty());
+    assert_eq!(log.record("a"), 1);
+    assert_eq!(log.record("b"), 2);
+    assert_eq!(log.1, 2);
+    assert_eq!(log.0.borrow().join(","), "a,b");
+}
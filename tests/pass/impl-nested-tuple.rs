@@ -0,0 +1,29 @@
+use delegate_attr::delegate;
+
+struct Outer(Middle);
+struct Middle(Vec<u8>);
+
+#[delegate(self.0.0)]
+impl Outer {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+    fn into_boxed_slice(self) -> Box<[u8]> {}
+}
+
+struct Deep(Outer);
+
+#[delegate(self.0 .0 .0)]
+impl Deep {
+    fn len(&self) -> usize {}
+    fn into_boxed_slice(self) -> Box<[u8]> {}
+}
+
+fn main() {
+    let mut outer = Outer(Middle(vec![1]));
+    outer.push(2);
+    assert_eq!(outer.len(), 2);
+    assert_eq!(&*outer.into_boxed_slice(), [1, 2]);
+    let deep = Deep(Outer(Middle(vec![1])));
+    assert_eq!(deep.len(), 1);
+    assert_eq!(&*deep.into_boxed_slice(), [1]);
+}
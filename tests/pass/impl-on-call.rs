@@ -0,0 +1,23 @@
+use delegate_attr::delegate;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn count(_name: &'static str) {
+    CALLS.fetch_add(1, Ordering::Relaxed);
+}
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0, on_call = count)]
+impl Foo {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+}
+
+fn main() {
+    let mut foo = Foo(vec![]);
+    foo.push(1);
+    assert_eq!(foo.len(), 1);
+    assert_eq!(CALLS.load(Ordering::Relaxed), 2);
+}
@@ -0,0 +1,54 @@
+use delegate_attr::delegate;
+use std::cell::{LazyCell, OnceCell};
+
+#[derive(Default)]
+struct Inner {
+    name: String,
+}
+
+impl Inner {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn len(&self) -> usize {
+        self.name.len()
+    }
+}
+
+struct Cfg(OnceCell<Inner>);
+
+#[delegate(self.0.get().unwrap())]
+impl Cfg {
+    fn name(&self) -> &str {}
+}
+
+// The initializer is given once in the receiver.
+#[delegate(self.0.get_or_init(Inner::default))]
+impl Cfg {
+    fn len(&self) -> usize {}
+}
+
+struct Lazy(LazyCell<Inner>);
+
+#[delegate(self.0)]
+impl Lazy {
+    fn name(&self) -> &str {}
+}
+
+fn main() {
+    let cfg = Cfg(OnceCell::new());
+    assert_eq!(cfg.len(), 0);
+    assert_eq!(cfg.name(), "");
+
+    let cfg = Cfg(OnceCell::new());
+    let _ = cfg.0.set(Inner {
+        name: "set".to_owned(),
+    });
+    assert_eq!(cfg.len(), 3);
+    assert_eq!(cfg.name(), "set");
+
+    let lazy = Lazy(LazyCell::new(|| Inner {
+        name: "lazy".to_owned(),
+    }));
+    assert_eq!(lazy.name(), "lazy");
+}
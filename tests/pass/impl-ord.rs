@@ -0,0 +1,39 @@
+use delegate_attr::delegate;
+use std::cmp::Ordering;
+
+#[derive(PartialEq, Eq)]
+struct Version(Vec<u32>);
+
+#[delegate(self.0)]
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {}
+}
+
+#[delegate(self.0)]
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {}
+}
+
+#[delegate(self.0)]
+impl Version {
+    fn append(&mut self, other: &mut Version) {}
+}
+
+fn main() {
+    let v1 = Version(vec![1, 2]);
+    let mut v2 = Version(vec![1, 10]);
+    assert_eq!(v1.cmp(&v2), Ordering::Less);
+    assert_eq!(v2.partial_cmp(&v1), Some(Ordering::Greater));
+    assert!(v1 < v2);
+    assert_eq!(v1.max(v2.clone_version()).0, [1, 10]);
+    let mut other = Version(vec![3]);
+    v2.append(&mut other);
+    assert_eq!(v2.0, [1, 10, 3]);
+    assert!(other.0.is_empty());
+}
+
+impl Version {
+    fn clone_version(&self) -> Self {
+        Version(self.0.clone())
+    }
+}
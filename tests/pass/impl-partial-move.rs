@@ -0,0 +1,43 @@
+use delegate_attr::delegate;
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+struct Wrapper {
+    inner: Inner,
+    name: String,
+}
+
+// Moving a field out of `self` leaves the other fields, e.g. `name`, to be dropped.
+#[delegate(self.inner)]
+impl Wrapper {
+    fn into_vec(self) -> Vec<u8> {}
+}
+
+#[delegate(self.inner.0)]
+impl Wrapper {
+    fn len(&self) -> usize {}
+}
+
+struct Tuple(String, Vec<u8>);
+
+#[delegate(self.1, owned = into_iter)]
+impl Tuple {
+    fn count(self) -> usize {}
+}
+
+fn main() {
+    let wrapper = Wrapper {
+        inner: Inner(vec![1, 2]),
+        name: "wrapper".to_owned(),
+    };
+    assert_eq!(wrapper.name, "wrapper");
+    assert_eq!(wrapper.len(), 2);
+    assert_eq!(wrapper.into_vec(), [1, 2]);
+    assert_eq!(Tuple("tuple".to_owned(), vec![1, 2, 3]).count(), 3);
+}
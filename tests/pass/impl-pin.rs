@@ -0,0 +1,67 @@
+use delegate_attr::delegate;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+trait Counter {
+    fn get(self: Pin<&Self>) -> u32;
+    fn bump(self: Pin<&mut Self>);
+}
+
+#[derive(Default)]
+struct Plain(u32);
+
+impl Counter for Plain {
+    fn get(self: Pin<&Self>) -> u32 {
+        self.0
+    }
+    fn bump(mut self: Pin<&mut Self>) {
+        self.0 += 1;
+    }
+}
+
+#[derive(Default)]
+struct Pinned(u32, PhantomPinned);
+
+impl Counter for Pinned {
+    fn get(self: Pin<&Self>) -> u32 {
+        self.0
+    }
+    fn bump(self: Pin<&mut Self>) {
+        unsafe { self.get_unchecked_mut().0 += 1 }
+    }
+}
+
+#[derive(Default)]
+struct Wrapper {
+    inner: Plain,
+}
+
+// `Plain` is `Unpin`, so no unsafe is needed.
+#[delegate(self.inner)]
+impl Counter for Wrapper {
+    fn get(self: Pin<&Self>) -> u32 {}
+    fn bump(self: Pin<&mut Self>) {}
+}
+
+#[derive(Default)]
+struct PinnedWrapper {
+    inner: Pinned,
+}
+
+// `inner` is never moved out of a pinned `PinnedWrapper`.
+#[delegate(self.inner, unsafe_pin)]
+impl Counter for PinnedWrapper {
+    fn get(self: Pin<&Self>) -> u32 {}
+    fn bump(self: Pin<&mut Self>) {}
+}
+
+fn main() {
+    let mut wrapper = Wrapper::default();
+    Pin::new(&mut wrapper).bump();
+    assert_eq!(Pin::new(&wrapper).get(), 1);
+
+    let mut pinned = Box::pin(PinnedWrapper::default());
+    pinned.as_mut().bump();
+    pinned.as_mut().bump();
+    assert_eq!(pinned.as_ref().get(), 2);
+}
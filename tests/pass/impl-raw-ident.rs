@@ -0,0 +1,33 @@
+use delegate_attr::delegate;
+
+struct Inner(u8);
+
+impl Inner {
+    fn r#type(&self) -> u8 {
+        self.0
+    }
+
+    fn r#match<T: From<u8>>(&self) -> T {
+        T::from(self.0)
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+#[rename(r#type = r#ty)]
+impl Wrapper {
+    fn r#type(&self) -> u8 {}
+    fn r#match<T: From<u8>>(&self) -> T {}
+    #[call(r#type)]
+    fn r#loop(&self) -> u8 {}
+    fn r#ty(&self) -> u8 {}
+}
+
+fn main() {
+    let wrapper = Wrapper(Inner(3));
+    assert_eq!(wrapper.r#type(), 3);
+    assert_eq!(wrapper.r#match::<u32>(), 3);
+    assert_eq!(wrapper.r#loop(), 3);
+    assert_eq!(wrapper.r#ty(), 3);
+}
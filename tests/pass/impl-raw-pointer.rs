@@ -0,0 +1,28 @@
+use delegate_attr::delegate;
+
+struct Handle(*mut Vec<u8>);
+
+impl Handle {
+    fn new() -> Self {
+        Handle(Box::into_raw(Box::default()))
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        drop(unsafe { Box::from_raw(self.0) });
+    }
+}
+
+#[delegate((*self.0), unsafe)]
+impl Handle {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+}
+
+fn main() {
+    let mut handle = Handle::new();
+    handle.push(1);
+    handle.push(2);
+    assert_eq!(handle.len(), 2);
+}
@@ -0,0 +1,20 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+
+struct Shared(RefCell<Vec<u8>>);
+
+// One block for all kinds of `self`, instead of one block per receiver.
+#[delegate(self.0, ref = borrow, mut = get_mut, owned = into_inner)]
+impl Shared {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+    fn into_boxed_slice(self) -> Box<[u8]> {}
+}
+
+fn main() {
+    let mut shared = Shared(RefCell::new(vec![]));
+    shared.push(1);
+    shared.push(2);
+    assert_eq!(shared.len(), 2);
+    assert_eq!(&*shared.into_boxed_slice(), [1, 2]);
+}
@@ -0,0 +1,22 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0)]
+#[rename(len = size, capacity = cap)]
+impl Foo {
+    fn size(&self) -> usize {}
+    fn cap(&self) -> usize {}
+    // #[call] takes precedence over #[rename].
+    #[call(is_empty)]
+    fn len(&self) -> bool {}
+    fn push(&mut self, value: u8) {}
+}
+
+fn main() {
+    let mut foo = Foo(Vec::with_capacity(4));
+    foo.push(1);
+    assert_eq!(foo.size(), 1);
+    assert_eq!(foo.cap(), 4);
+    assert!(!foo.len());
+}
@@ -0,0 +1,51 @@
+use delegate_attr::delegate;
+
+#[derive(Default)]
+struct Options {
+    width: u32,
+    names: Vec<String>,
+}
+
+impl Options {
+    fn width(&mut self, width: u32) -> &mut Self {
+        self.width = width;
+        self
+    }
+    fn name(&mut self, name: &str) -> &mut Self {
+        self.names.push(name.to_owned());
+        self
+    }
+    fn check(&self) -> &Self {
+        assert!(self.width > 0);
+        self
+    }
+}
+
+#[derive(Default)]
+struct Builder {
+    options: Options,
+    built: bool,
+}
+
+#[delegate(self.options)]
+impl Builder {
+    fn width(&mut self, width: u32) -> &mut Self {}
+    fn name(&mut self, name: &str) -> &mut Builder {}
+    fn check<'a>(&'a self) -> &'a Self {}
+}
+
+impl Builder {
+    fn build(&mut self) -> &mut Self {
+        self.built = true;
+        self
+    }
+}
+
+fn main() {
+    let mut builder = Builder::default();
+    // The wrapper is returned, so its own methods can be chained.
+    builder.width(3).name("a").build().name("b").check();
+    assert_eq!(builder.options.width, 3);
+    assert_eq!(builder.options.names, ["a", "b"]);
+    assert!(builder.built);
+}
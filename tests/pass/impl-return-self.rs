@@ -0,0 +1,44 @@
+use delegate_attr::delegate;
+
+#[derive(Debug, PartialEq)]
+struct Foo<T>(Vec<T>);
+
+#[delegate(self.0)]
+impl<T: Clone> Clone for Foo<T> {
+    fn clone(&self) -> Self {}
+}
+
+#[delegate(self.0)]
+impl<T> Foo<T> {
+    fn split_off(&mut self, at: usize) -> Foo<T> {}
+}
+
+type Bytes = Foo<u8>;
+
+#[delegate(self.0)]
+impl Bytes {
+    #[call(split_off)]
+    fn split_bytes(&mut self, at: usize) -> Bytes {}
+}
+
+#[derive(Debug, PartialEq)]
+struct Bar {
+    inner: Vec<u8>,
+}
+
+#[delegate(self.inner)]
+impl Bar {
+    #[call(split_off)]
+    fn split(&mut self, at: usize) -> Self {}
+}
+
+fn main() {
+    let mut foo = Foo(vec![1, 2, 3]);
+    assert_eq!(foo.clone(), Foo(vec![1, 2, 3]));
+    assert_eq!(foo.split_off(2), Foo(vec![3]));
+    assert_eq!(foo.split_bytes(1), Foo(vec![2]));
+    assert_eq!(foo, Foo(vec![1]));
+
+    let mut bar = Bar { inner: vec![1, 2] };
+    assert_eq!(bar.split(1), Bar { inner: vec![2] });
+}
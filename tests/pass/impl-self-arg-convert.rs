@@ -0,0 +1,65 @@
+#![deny(unused_parens, unused_unsafe)]
+
+use delegate_attr::delegate;
+use std::cmp::Ordering;
+
+struct Lazy(Option<u8>);
+
+#[delegate(self.0, unwrap)]
+impl Lazy {
+    fn cmp(&self, other: &Self) -> Ordering {}
+    fn eq(&self, other: &Self) -> bool {}
+}
+
+struct Named(Result<String, ()>);
+
+#[delegate(self.0, expect = "no name")]
+impl Named {
+    fn cmp(&self, other: &Self) -> Ordering {}
+    fn push_str(&mut self, string: &str) {}
+}
+
+struct Text(String);
+
+#[delegate(self.0, as_ref = str)]
+impl Text {
+    fn cmp(&self, other: &Self) -> Ordering {}
+}
+
+#[derive(Clone, Copy)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+struct Float {
+    bits: Bits,
+}
+
+#[delegate(self.bits.float, unsafe)]
+impl Float {
+    fn total_cmp(&self, other: &Self) -> Ordering {}
+    fn max(self, other: Self) -> f32 {}
+}
+
+fn main() {
+    assert_eq!(Lazy(Some(1)).cmp(&Lazy(Some(2))), Ordering::Less);
+    assert!(Lazy(Some(1)).eq(&Lazy(Some(1))));
+
+    let mut name = Named(Ok("a".to_owned()));
+    name.push_str("b");
+    assert_eq!(name.cmp(&Named(Ok("ab".to_owned()))), Ordering::Equal);
+
+    let text = Text("b".to_owned());
+    assert_eq!(text.cmp(&Text("a".to_owned())), Ordering::Greater);
+
+    let one = Float {
+        bits: Bits { float: 1.0 },
+    };
+    let two = Float {
+        bits: Bits { float: 2.0 },
+    };
+    assert_eq!(one.total_cmp(&two), Ordering::Less);
+    assert_eq!(one.max(two), 2.0);
+    let _ = Bits { int: 0 };
+}
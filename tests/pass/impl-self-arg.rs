@@ -0,0 +1,44 @@
+use delegate_attr::delegate;
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    fn merge(mut self, mut other: Inner) -> Inner {
+        self.0.append(&mut other.0);
+        self
+    }
+    fn zip(self, other: Inner) -> Vec<(u8, u8)> {
+        self.0.into_iter().zip(other.0).collect()
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+impl Wrapper {
+    fn merge(self, other: Self) -> Self {}
+    fn zip(self, other: Wrapper) -> Vec<(u8, u8)> {}
+}
+
+struct Named {
+    inner: Inner,
+}
+
+#[delegate(self.inner)]
+impl Named {
+    fn merge(self, other: Self) -> Self {}
+}
+
+fn main() {
+    let merged = Wrapper(Inner(vec![1])).merge(Wrapper(Inner(vec![2])));
+    assert_eq!((merged.0).0, [1, 2]);
+    let zipped = Wrapper(Inner(vec![1, 2])).zip(Wrapper(Inner(vec![3, 4])));
+    assert_eq!(zipped, [(1, 3), (2, 4)]);
+    let merged = Named {
+        inner: Inner(vec![1]),
+    }
+    .merge(Named {
+        inner: Inner(vec![2]),
+    });
+    assert_eq!(merged.inner.0, [1, 2]);
+}
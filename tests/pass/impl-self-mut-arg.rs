@@ -0,0 +1,38 @@
+use delegate_attr::delegate;
+use std::collections::BTreeSet;
+
+struct Bytes(Vec<u8>);
+
+#[delegate(self.0)]
+impl Bytes {
+    fn append(&mut self, other: &mut Self) {}
+    fn len(&self) -> usize {}
+}
+
+struct Set {
+    items: BTreeSet<u8>,
+}
+
+#[delegate(self.items)]
+impl Set {
+    fn append(&mut self, other: &mut Set) {}
+    fn is_subset(&self, other: &Self) -> bool {}
+}
+
+fn main() {
+    let mut a = Bytes(vec![1]);
+    let mut b = Bytes(vec![2, 3]);
+    a.append(&mut b);
+    assert_eq!(a.0, [1, 2, 3]);
+    assert_eq!(b.len(), 0);
+
+    let mut a = Set {
+        items: vec![1].into_iter().collect(),
+    };
+    let mut b = Set {
+        items: vec![2].into_iter().collect(),
+    };
+    a.append(&mut b);
+    assert!(b.items.is_empty());
+    assert!(b.is_subset(&a));
+}
@@ -0,0 +1,75 @@
+use delegate_attr::delegate;
+use std::iter::FromIterator;
+
+struct Bytes(std::vec::IntoIter<u8>);
+
+#[delegate(self.0)]
+impl Iterator for Bytes {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {}
+    fn count(self) -> usize
+    where
+        Self: Sized,
+    {
+    }
+    fn collect<B: FromIterator<u8>>(self) -> B
+    where
+        Self: Sized,
+    {
+    }
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, u8) -> B,
+    {
+    }
+}
+
+trait Source {
+    fn name(&self) -> String;
+    fn into_names<B: FromIterator<String>>(self) -> B
+    where
+        Self: Sized;
+}
+
+struct Names(Vec<String>);
+
+impl Source for Names {
+    fn name(&self) -> String {
+        self.0.join(",")
+    }
+    fn into_names<B: FromIterator<String>>(self) -> B
+    where
+        Self: Sized,
+    {
+        self.0.into_iter().collect()
+    }
+}
+
+struct Named {
+    names: Names,
+}
+
+#[delegate(self.names)]
+impl Source for Named {
+    fn name(&self) -> String {}
+    fn into_names<B: FromIterator<String>>(self) -> B
+    where
+        Self: Sized,
+    {
+    }
+}
+
+fn main() {
+    assert_eq!(Bytes(vec![1, 2].into_iter()).count(), 2);
+    assert_eq!(Bytes(vec![1, 2].into_iter()).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(Bytes(vec![1, 2].into_iter()).fold(0, |a, b| a + b), 3);
+
+    let named = Named {
+        names: Names(vec!["a".to_owned(), "b".to_owned()]),
+    };
+    assert_eq!(named.name(), "a,b");
+    let source: &dyn Source = &named;
+    assert_eq!(source.name(), "a,b");
+    assert_eq!(named.into_names::<Vec<_>>(), ["a", "b"]);
+}
@@ -0,0 +1,52 @@
+use delegate_attr::delegate;
+use std::collections::HashMap;
+
+struct Statement(String);
+
+impl Statement {
+    fn query(&self, limit: usize) -> Vec<String> {
+        vec![self.0.clone(); limit]
+    }
+}
+
+struct Connection;
+
+impl Connection {
+    fn prepare(&self, sql: &str) -> Statement {
+        Statement(sql.to_owned())
+    }
+}
+
+struct Pool(Connection);
+
+impl Pool {
+    fn get(&self) -> &Connection {
+        &self.0
+    }
+}
+
+struct Db {
+    pool: Pool,
+    tables: HashMap<String, Vec<u32>>,
+}
+
+#[delegate(self.pool.get())]
+impl Db {
+    #[to(self.pool.get().prepare(sql))]
+    fn query(&self, #[skip] sql: &str, limit: usize) -> Vec<String> {}
+
+    #[to(self.tables.entry(name.into()).or_default())]
+    #[call(push)]
+    fn insert<N: Into<String>>(&mut self, #[skip] name: N, row: u32) {}
+}
+
+fn main() {
+    let mut db = Db {
+        pool: Pool(Connection),
+        tables: HashMap::new(),
+    };
+    assert_eq!(db.query("select", 2), ["select", "select"]);
+    db.insert("a", 1);
+    db.insert(String::from("a"), 2);
+    assert_eq!(db.tables["a"], [1, 2]);
+}
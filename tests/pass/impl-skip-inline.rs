@@ -0,0 +1,16 @@
+use delegate_attr::delegate;
+
+struct Foo(Vec<u8>);
+
+#[delegate(self.0, skip_inline)]
+impl Foo {
+    fn len(&self) -> usize {}
+    #[inline_always]
+    fn is_empty(&self) -> bool {}
+}
+
+fn main() {
+    let foo = Foo(vec![1]);
+    assert_eq!(foo.len(), 1);
+    assert!(!foo.is_empty());
+}
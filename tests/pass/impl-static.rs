@@ -0,0 +1,36 @@
+use delegate_attr::delegate;
+use std::cell::Cell;
+use std::sync::Mutex;
+
+thread_local! {
+    static COUNTER: Cell<u32> = const { Cell::new(0) };
+}
+
+static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Counter;
+
+#[delegate(COUNTER)]
+impl Counter {
+    #[allow_missing_self]
+    fn get() -> u32 {}
+    #[allow_missing_self]
+    fn set(value: u32) {}
+}
+
+struct Log;
+
+#[delegate(LOG.lock().unwrap())]
+impl Log {
+    #[allow_missing_self]
+    fn push(message: String) {}
+    #[allow_missing_self]
+    fn len() -> usize {}
+}
+
+fn main() {
+    Counter::set(Counter::get() + 1);
+    assert_eq!(Counter::get(), 1);
+    Log::push("hello".to_owned());
+    assert_eq!(Log::len(), 1);
+}
@@ -0,0 +1,32 @@
+use delegate_attr::delegate;
+use std::collections::BTreeMap;
+
+struct Writer {
+    buffer: Vec<u8>,
+    index: BTreeMap<u8, String>,
+}
+
+#[delegate(self.buffer)]
+impl Writer {
+    fn push(&mut self, value: u8) {}
+    fn len(&self) -> usize {}
+    #[take]
+    fn take_buffer(&mut self) -> Vec<u8> {}
+    #[take]
+    #[to(self.index)]
+    fn take_index(&mut self) -> BTreeMap<u8, String> {}
+}
+
+fn main() {
+    let mut writer = Writer {
+        buffer: vec![],
+        index: BTreeMap::new(),
+    };
+    writer.push(1);
+    writer.push(2);
+    writer.index.insert(1, "a".to_owned());
+    assert_eq!(writer.take_buffer(), [1, 2]);
+    assert_eq!(writer.len(), 0);
+    assert_eq!(writer.take_index().len(), 1);
+    assert!(writer.index.is_empty());
+}
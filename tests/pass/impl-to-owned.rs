@@ -0,0 +1,41 @@
+use delegate_attr::delegate;
+use std::path::{Path, PathBuf};
+
+struct Person {
+    name: String,
+    home: PathBuf,
+}
+
+impl Person {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn home(&self) -> &Path {
+        &self.home
+    }
+}
+
+struct Wrapper(Person);
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[to_owned]
+    fn name(&self) -> String {}
+    #[to_owned]
+    fn home(&self) -> PathBuf {}
+    #[to_owned]
+    #[call(name)]
+    #[into]
+    fn boxed_name(&self) -> Box<str> {}
+}
+
+fn main() {
+    let wrapper = Wrapper(Person {
+        name: "foo".to_owned(),
+        home: PathBuf::from("/home/foo"),
+    });
+    let name: String = wrapper.name();
+    assert_eq!(name, "foo");
+    assert_eq!(wrapper.home(), Path::new("/home/foo"));
+    assert_eq!(&*wrapper.boxed_name(), "foo");
+}
@@ -0,0 +1,63 @@
+use delegate_attr::delegate;
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+struct Foo {
+    names: Vec<String>,
+    ids: Mutex<Vec<u32>>,
+    tags: RefCell<Vec<&'static str>>,
+    more_tags: RefCell<Vec<&'static str>>,
+    pair: (u8, Vec<u8>),
+}
+
+#[delegate(self.names)]
+impl Foo {
+    fn len(&self) -> usize {}
+    fn push(&mut self, name: String) {}
+
+    #[to(self.ids.lock().unwrap())]
+    #[call(push)]
+    fn push_id(&self, id: u32) {}
+
+    #[to(self.ids.lock().unwrap())]
+    #[call(len)]
+    fn id_count(&self) -> usize {}
+
+    #[to(self.tags.borrow_mut())]
+    #[call(push)]
+    fn tag(&self, tag: &'static str) {}
+
+    #[to(self.pair.1)]
+    #[call(len)]
+    fn second_len(&self) -> usize {}
+}
+
+// Options of the block still apply to overridden receivers.
+#[delegate(self.tags, ref = borrow, mut = get_mut)]
+impl Foo {
+    #[call(len)]
+    fn tag_count(&self) -> usize {}
+
+    #[to(self.more_tags)]
+    #[call(len)]
+    fn more_tag_count(&self) -> usize {}
+}
+
+fn main() {
+    let mut foo = Foo {
+        names: vec![],
+        ids: Mutex::new(vec![]),
+        tags: RefCell::new(vec![]),
+        more_tags: RefCell::new(vec!["y", "z"]),
+        pair: (0, vec![1, 2]),
+    };
+    foo.push("a".to_owned());
+    foo.push_id(1);
+    foo.push_id(2);
+    foo.tag("x");
+    assert_eq!(foo.len(), 1);
+    assert_eq!(foo.id_count(), 2);
+    assert_eq!(foo.tag_count(), 1);
+    assert_eq!(foo.more_tag_count(), 2);
+    assert_eq!(foo.second_len(), 2);
+}
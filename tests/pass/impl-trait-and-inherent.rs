@@ -0,0 +1,26 @@
+use delegate_attr::delegate;
+
+struct Iter(std::vec::IntoIter<u8>);
+
+#[delegate(self.0)]
+impl Iterator for Iter {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {}
+    fn size_hint(&self) -> (usize, Option<usize>) {}
+}
+
+#[delegate(self.0)]
+impl Iter {
+    // Same name as the trait method, called in preference to it.
+    fn next(&mut self) -> Option<u8> {}
+    fn as_slice(&self) -> &[u8] {}
+}
+
+fn main() {
+    let mut iter = Iter(vec![1, 2, 3].into_iter());
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(Iterator::next(&mut iter), Some(2));
+    assert_eq!(iter.as_slice(), [3]);
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.collect::<Vec<_>>(), [3]);
+}
@@ -0,0 +1,41 @@
+use delegate_attr::delegate;
+use std::fmt::Display;
+
+struct Inner {
+    name: String,
+    id: u32,
+}
+
+impl Inner {
+    fn name(&self) -> &String {
+        &self.name
+    }
+    fn describe(&self) -> &dyn Display {
+        &self.id
+    }
+    fn boxed_id(&self) -> Box<u32> {
+        Box::new(self.id)
+    }
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+impl Wrapper {
+    // coerced from &String
+    fn name(&self) -> &dyn Display {}
+    fn describe(&self) -> &dyn Display {}
+    // coerced from Box<u32>
+    #[call(boxed_id)]
+    fn boxed(&self) -> Box<dyn Display> {}
+}
+
+fn main() {
+    let wrapper = Wrapper(Inner {
+        name: "foo".to_owned(),
+        id: 1,
+    });
+    assert_eq!(wrapper.name().to_string(), "foo");
+    assert_eq!(wrapper.describe().to_string(), "1");
+    assert_eq!(wrapper.boxed().to_string(), "1");
+}
@@ -0,0 +1,78 @@
+#![deny(unused_must_use, redundant_semicolons)]
+
+use delegate_attr::delegate;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+struct Inner(Vec<u8>);
+
+impl Inner {
+    #[must_use]
+    fn push(&mut self, value: u8) -> usize {
+        self.0.push(value);
+        self.0.len()
+    }
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+    async fn extend(&mut self, values: &[u8]) {
+        self.0.extend_from_slice(values);
+    }
+}
+
+enum Either {
+    Left(Inner),
+    Right(Inner),
+}
+
+struct Wrapper(Inner);
+
+#[delegate(self.0)]
+impl Wrapper {
+    #[discard]
+    fn push(&mut self, value: u8) {}
+    fn clear(&mut self) {}
+    async fn extend(&mut self, values: &[u8]) {}
+}
+
+#[delegate(self.0, unsafe)]
+impl Wrapper {
+    #[discard]
+    #[call(push)]
+    fn push_unsafe(&mut self, value: u8) {}
+    #[call(clear)]
+    fn clear_unsafe(&mut self) {}
+}
+
+#[delegate(enum(Left, Right))]
+impl Either {
+    #[discard]
+    fn push(&mut self, value: u8) {}
+    fn clear(&mut self) {}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = Pin::as_mut(&mut future).poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn main() {
+    let mut wrapper = Wrapper(Inner(vec![]));
+    wrapper.push(1);
+    wrapper.push_unsafe(2);
+    block_on(wrapper.extend(&[3]));
+    assert_eq!(wrapper.0 .0, [1, 2, 3]);
+    wrapper.clear();
+    wrapper.clear_unsafe();
+    assert!(wrapper.0 .0.is_empty());
+
+    let mut either = Either::Right(Inner(vec![]));
+    either.push(1);
+    either.clear();
+}
@@ -0,0 +1,36 @@
+use delegate_attr::delegate;
+use std::cell::UnsafeCell;
+
+struct Slab(UnsafeCell<Vec<u8>>);
+
+#[delegate(unsafe { &*self.0.get() })]
+impl Slab {
+    fn len(&self) -> usize {}
+    fn first(&self) -> Option<&u8> {}
+    #[to(unsafe { &mut *self.0.get() })]
+    fn push(&mut self, value: u8) {}
+    #[to(unsafe { &mut *self.0.get() })]
+    fn extend_from_slice(&mut self, other: &[u8]) -> &mut Self {}
+}
+
+// The same via the `unsafe` option, which wraps the access to the target the same way.
+struct Cells(UnsafeCell<Vec<u8>>);
+
+#[delegate(*self.0.get(), unsafe)]
+impl Cells {
+    fn len(&self) -> usize {}
+    fn push(&mut self, value: u8) {}
+}
+
+fn main() {
+    let mut slab = Slab(UnsafeCell::new(vec![]));
+    slab.push(1);
+    assert_eq!(slab.len(), 1);
+    assert_eq!(slab.first(), Some(&1));
+    assert_eq!(slab.extend_from_slice(&[2, 3]).len(), 3);
+
+    let mut cells = Cells(UnsafeCell::new(vec![]));
+    cells.push(1);
+    cells.push(2);
+    assert_eq!(cells.len(), 2);
+}
@@ -0,0 +1,62 @@
+#![deny(unused_parens, unused_unsafe)]
+
+use delegate_attr::delegate;
+
+#[derive(Clone, Copy)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+struct Float {
+    bits: Bits,
+}
+
+#[delegate(self.bits.float, unsafe)]
+impl Float {
+    fn is_sign_negative(&self) -> bool {}
+    fn abs(self) -> f32 {}
+}
+
+#[delegate(self.bits.int, unsafe)]
+impl Float {
+    fn count_ones(&self) -> u32 {}
+    #[call(Clone::clone)]
+    fn to_bits(&self) -> u32 {}
+}
+
+union Slot {
+    bytes: std::mem::ManuallyDrop<Vec<u8>>,
+}
+
+struct Buffer {
+    slot: Slot,
+}
+
+#[delegate(*self.slot.bytes, unsafe)]
+impl Buffer {
+    fn push(&mut self, value: u8) {}
+    fn len(&self) -> usize {}
+    #[take]
+    fn take(&mut self) -> Vec<u8> {}
+}
+
+fn main() {
+    let float = Float {
+        bits: Bits { float: -1.0 },
+    };
+    assert!(float.is_sign_negative());
+    assert_eq!(float.count_ones(), (-1.0f32).to_bits().count_ones());
+    assert_eq!(float.to_bits(), (-1.0f32).to_bits());
+    assert_eq!(float.abs(), 1.0);
+
+    let mut buffer = Buffer {
+        slot: Slot {
+            bytes: std::mem::ManuallyDrop::new(vec![]),
+        },
+    };
+    buffer.push(1);
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer.take(), [1]);
+    assert_eq!(buffer.len(), 0);
+}
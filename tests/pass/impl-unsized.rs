@@ -0,0 +1,49 @@
+use delegate_attr::delegate;
+use std::fmt::Display;
+
+struct Bytes(Box<[u8]>);
+
+#[delegate(self.0)]
+impl Bytes {
+    fn len(&self) -> usize {}
+    fn first(&self) -> Option<&u8> {}
+    fn sort(&mut self) {}
+}
+
+struct Wrapper<T: ?Sized>(Box<T>);
+
+#[delegate(self.0)]
+impl<T: ?Sized + Display> Wrapper<T> {
+    fn to_string(&self) -> String {}
+}
+
+#[delegate(self.0)]
+impl<T> Wrapper<[T]>
+where
+    T: Ord,
+{
+    fn len(&self) -> usize {}
+    fn sort(&mut self) {}
+}
+
+// dereference the box, otherwise `AsRef<T>` of `Box<T>` gets picked
+#[delegate((*self.0))]
+impl<T: ?Sized + AsRef<str>> AsRef<str> for Wrapper<T> {
+    fn as_ref(&self) -> &str {}
+}
+
+fn main() {
+    let mut bytes = Bytes(vec![2, 1].into_boxed_slice());
+    bytes.sort();
+    assert_eq!(bytes.len(), 2);
+    assert_eq!(bytes.first(), Some(&1));
+
+    let wrapper: Wrapper<str> = Wrapper("foo".into());
+    assert_eq!(wrapper.to_string(), "foo");
+    assert_eq!(wrapper.as_ref(), "foo");
+
+    let mut slice: Wrapper<[u8]> = Wrapper(vec![3, 1, 2].into_boxed_slice());
+    slice.sort();
+    assert_eq!(slice.len(), 3);
+    assert_eq!(*slice.0, [1, 2, 3]);
+}
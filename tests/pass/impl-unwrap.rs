@@ -0,0 +1,42 @@
+use delegate_attr::delegate;
+
+trait Plugin {
+    fn name(&self) -> String;
+    fn run(&mut self, input: u32) -> u32;
+}
+
+struct Double;
+
+impl Plugin for Double {
+    fn name(&self) -> String {
+        "double".to_owned()
+    }
+    fn run(&mut self, input: u32) -> u32 {
+        input * 2
+    }
+}
+
+struct Host(Option<Box<dyn Plugin>>);
+
+#[delegate(self.0, unwrap)]
+impl Host {
+    fn name(&self) -> String {}
+    fn run(&mut self, input: u32) -> u32 {}
+    #[propagate]
+    #[call(name)]
+    fn try_name(&self) -> Option<String> {}
+    #[propagate]
+    #[call(run)]
+    fn try_run(&mut self, input: u32) -> Option<u32> {}
+}
+
+fn main() {
+    let mut host = Host(Some(Box::new(Double)));
+    assert_eq!(host.name(), "double");
+    assert_eq!(host.run(2), 4);
+    assert_eq!(host.try_run(3), Some(6));
+    let mut host = Host(None);
+    assert_eq!(host.try_name(), None);
+    assert_eq!(host.try_run(3), None);
+    assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| host.name())).is_err());
+}
@@ -0,0 +1,44 @@
+use delegate_attr::delegate;
+
+mod ext {
+    pub trait Double {
+        fn double(&self) -> Self;
+    }
+
+    impl Double for u32 {
+        fn double(&self) -> u32 {
+            self * 2
+        }
+    }
+
+    pub trait Halve {
+        fn halve(&self) -> Self;
+    }
+
+    impl Halve for u32 {
+        fn halve(&self) -> u32 {
+            self / 2
+        }
+    }
+}
+
+struct Number(u32);
+
+#[delegate(self.0, use = ext::Double)]
+impl Number {
+    fn double(&self) -> u32 {}
+}
+
+struct Other(u32);
+
+#[delegate(self.0, use = crate::ext::{Double, Halve as _})]
+impl Other {
+    fn double(&self) -> u32 {}
+    fn halve(&self) -> u32 {}
+}
+
+fn main() {
+    assert_eq!(Number(2).double(), 4);
+    assert_eq!(Other(4).double(), 8);
+    assert_eq!(Other(4).halve(), 2);
+}
@@ -0,0 +1,42 @@
+use delegate_attr::delegate;
+
+struct Buf(Vec<u8>);
+
+#[delegate(self.0, via = as_slice)]
+impl Buf {
+    fn len(&self) -> usize {}
+    fn first(&self) -> Option<&u8> {}
+    fn contains(&self, value: &u8) -> bool {}
+    fn starts_with(&self, prefix: &[u8]) -> bool {}
+}
+
+#[delegate(self.0, via = as_mut_slice)]
+impl Buf {
+    fn reverse(&mut self) {}
+    fn fill(&mut self, value: u8) {}
+}
+
+struct Text(String);
+
+#[delegate(self.0, via = as_bytes)]
+impl Text {
+    #[call(len)]
+    fn byte_len(&self) -> usize {}
+    fn is_ascii(&self) -> bool {}
+}
+
+fn main() {
+    let mut buf = Buf(vec![1, 2, 3]);
+    assert_eq!(buf.len(), 3);
+    assert_eq!(buf.first(), Some(&1));
+    assert!(buf.contains(&2));
+    assert!(buf.starts_with(&[1, 2]));
+    buf.reverse();
+    assert_eq!(buf.0, [3, 2, 1]);
+    buf.fill(0);
+    assert_eq!(buf.0, [0, 0, 0]);
+
+    let text = Text("héllo".to_owned());
+    assert_eq!(text.byte_len(), 6);
+    assert!(!text.is_ascii());
+}
@@ -0,0 +1,24 @@
+mod foo {
+    pub mod bar {
+        use delegate_attr::delegate;
+
+        pub struct Foo(pub Vec<u8>);
+
+        #[delegate(self.0)]
+        #[vis(pub(in crate::foo))]
+        impl Foo {
+            fn len(&self) -> usize {}
+            pub fn is_empty(&self) -> bool {}
+        }
+    }
+
+    pub fn len(foo: &bar::Foo) -> usize {
+        foo.len()
+    }
+}
+
+fn main() {
+    let foo = foo::bar::Foo(vec![1]);
+    assert_eq!(foo::len(&foo), 1);
+    assert!(!foo.is_empty());
+}
@@ -0,0 +1,26 @@
+use delegate_attr::delegate;
+
+struct Wrapper<T>(Vec<T>);
+
+impl<T> Wrapper<T> {
+    #[delegate(self.0, where T: Clone)]
+    fn to_vec(&self) -> Vec<T> {}
+
+    #[delegate(self.0, inline = never, where T: Ord, T: Clone)]
+    fn sort(&mut self) {}
+}
+
+#[delegate(self.0, where T: PartialEq)]
+impl<T> Wrapper<T> {
+    fn contains(&self, x: &T) -> bool {}
+}
+
+fn main() {
+    let mut wrapper = Wrapper(vec![2, 1]);
+    wrapper.sort();
+    assert_eq!(wrapper.to_vec(), [1, 2]);
+    assert!(wrapper.contains(&1));
+    // Methods with extra bounds are still usable on other element types.
+    struct NotClone;
+    assert!(Wrapper(vec![NotClone]).0.len() == 1);
+}
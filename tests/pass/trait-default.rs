@@ -0,0 +1,29 @@
+use delegate_attr::delegate;
+
+trait HasInner {
+    fn inner(&self) -> &Vec<u8>;
+    fn inner_mut(&mut self) -> &mut Vec<u8>;
+
+    #[delegate(self.inner())]
+    fn len(&self) -> usize {}
+
+    #[delegate(self.inner_mut())]
+    fn push(&mut self, value: u8) {}
+}
+
+struct Foo(Vec<u8>);
+
+impl HasInner for Foo {
+    fn inner(&self) -> &Vec<u8> {
+        &self.0
+    }
+    fn inner_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.0
+    }
+}
+
+fn main() {
+    let mut foo = Foo(vec![1]);
+    foo.push(2);
+    assert_eq!(foo.len(), 2);
+}